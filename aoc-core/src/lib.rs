@@ -0,0 +1,41 @@
+use std::fmt::{self, Display};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "part 1"),
+            Part::Two => write!(f, "part 2"),
+        }
+    }
+}
+
+/// A single day's puzzle: how to turn the raw input into something both parts
+/// can work on, and how to answer each part from it.
+pub trait Solution {
+    /// The parsed puzzle input shared by both parts.
+    type Input;
+    type Answer: Display;
+    type Error: std::error::Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error>;
+    /// Solving can fail too, say if the answer does not fit in its type.
+    fn part_one(input: &Self::Input) -> Result<Self::Answer, Self::Error>;
+    fn part_two(input: &Self::Input) -> Result<Self::Answer, Self::Error>;
+
+    fn solve(input: &Self::Input, part: Part) -> Result<Self::Answer, Self::Error> {
+        match part {
+            Part::One => Self::part_one(input),
+            Part::Two => Self::part_two(input),
+        }
+    }
+}
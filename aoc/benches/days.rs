@@ -0,0 +1,135 @@
+//! Times each day's parse and solve stages separately, on the worked example
+//! and on the real input when there is one.
+//!
+//! Run with `cargo bench -p aoc --bench days`. The usual criterion filters
+//! apply, so `cargo bench -p aoc --bench days -- day6/input` only runs day 6
+//! against its real input. Once everything has run, a summary table of the
+//! mean time per stage is printed.
+
+use std::{
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use aoc_core::Solution;
+use criterion::{measurement::WallTime, BenchmarkGroup, Criterion};
+
+/// Mean time per stage for one day and input; `None` if the stage was
+/// filtered out.
+struct Row {
+    day: u8,
+    input: &'static str,
+    parse: Option<Duration>,
+    part_one: Option<Duration>,
+    part_two: Option<Duration>,
+}
+
+/// Benchmarks `routine` as `stage` in `group`, returning its mean time per
+/// iteration over everything criterion ran, warm-up included.
+fn measure<O>(
+    group: &mut BenchmarkGroup<WallTime>,
+    stage: &str,
+    mut routine: impl FnMut() -> O,
+) -> Option<Duration> {
+    let mut total = Duration::ZERO;
+    let mut runs = 0;
+    group.bench_function(stage, |bencher| {
+        bencher.iter_custom(|iterations| {
+            let start = Instant::now();
+            for _ in 0..iterations {
+                black_box(routine());
+            }
+            let elapsed = start.elapsed();
+            total += elapsed;
+            runs += iterations;
+            elapsed
+        })
+    });
+
+    (runs > 0).then(|| total.div_f64(runs as f64))
+}
+
+fn bench_day<S: Solution>(criterion: &mut Criterion, rows: &mut Vec<Row>, day: u8, example: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{}", day))
+        .join("input");
+    let real = std::fs::read_to_string(path).ok();
+
+    let inputs = [("example", Some(example)), ("input", real.as_deref())];
+    for (name, input) in inputs {
+        let Some(input) = input else {
+            continue;
+        };
+        let parsed = match S::parse(input) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                eprintln!("day {} {}: {}", day, name, e);
+                continue;
+            }
+        };
+
+        let mut group = criterion.benchmark_group(format!("day{}/{}", day, name));
+        if name == "input" {
+            // Some real inputs take a good fraction of a second per run
+            group.sample_size(10);
+        }
+        let parse = measure(&mut group, "parse", || S::parse(black_box(input)));
+        let part_one = measure(&mut group, "part 1", || S::part_one(black_box(&parsed)));
+        let part_two = measure(&mut group, "part 2", || S::part_two(black_box(&parsed)));
+        group.finish();
+
+        rows.push(Row {
+            day,
+            input: name,
+            parse,
+            part_one,
+            part_two,
+        });
+    }
+}
+
+fn print_summary(rows: &[Row]) {
+    let cell =
+        |time: Option<Duration>| time.map_or("-".to_string(), |time| format!("{:.2?}", time));
+
+    println!();
+    println!(
+        "{:>3}  {:<8} {:>12} {:>12} {:>12}",
+        "day", "input", "parse", "part 1", "part 2"
+    );
+    for row in rows {
+        if row.parse.is_none() && row.part_one.is_none() && row.part_two.is_none() {
+            continue;
+        }
+        println!(
+            "{:>3}  {:<8} {:>12} {:>12} {:>12}",
+            row.day,
+            row.input,
+            cell(row.parse),
+            cell(row.part_one),
+            cell(row.part_two)
+        );
+    }
+}
+
+fn main() {
+    let mut criterion = Criterion::default().configure_from_args();
+    let mut rows = Vec::new();
+
+    bench_day::<day1::Day1>(&mut criterion, &mut rows, 1, day1::EXAMPLE);
+    bench_day::<day2::Day2>(&mut criterion, &mut rows, 2, day2::EXAMPLE);
+    bench_day::<day3::Day3>(&mut criterion, &mut rows, 3, day3::EXAMPLE);
+    bench_day::<day4::Day4>(&mut criterion, &mut rows, 4, day4::EXAMPLE);
+    bench_day::<day5::Day5>(&mut criterion, &mut rows, 5, day5::EXAMPLE);
+    bench_day::<day6::Day6>(&mut criterion, &mut rows, 6, day6::EXAMPLE);
+    bench_day::<day7::Day7>(&mut criterion, &mut rows, 7, day7::EXAMPLE);
+    bench_day::<day8::Day8>(&mut criterion, &mut rows, 8, day8::EXAMPLE);
+    bench_day::<day9::Day9>(&mut criterion, &mut rows, 9, day9::EXAMPLE);
+    bench_day::<day10::Day10>(&mut criterion, &mut rows, 10, day10::EXAMPLE);
+    bench_day::<day11::Day11>(&mut criterion, &mut rows, 11, day11::EXAMPLE);
+
+    criterion.final_summary();
+    print_summary(&rows);
+}
@@ -0,0 +1,75 @@
+//! Compares the day 3 parser against the scanner it replaced on a few
+//! megabytes of generated memory where the instructions are few and far
+//! between.
+//!
+//! Run with `cargo bench -p aoc --bench scanner`. The old scanner looked for
+//! each operation name separately from the current position, so every step
+//! rescanned the rest of the memory for whichever names were not there. It is
+//! kept here, without the parsing that followed it, as the baseline.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Bytes of generated memory per benchmark input.
+const SIZE: usize = 4 << 20;
+
+/// Corrupted memory with a `mul` every few kilobytes and no `don't()` at all,
+/// which is the worst case for rescanning.
+fn sparse_memory() -> String {
+    const JUNK: &[u8] = b"!@#$%^&*()[]{}<>?,;: +-_='xmdo";
+
+    // A fixed linear congruential generator, so every run sees the same input
+    let mut state: u64 = 0x2024_1203;
+    let mut next = move || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (state >> 33) as usize
+    };
+
+    let mut memory = String::with_capacity(SIZE + 16);
+    while memory.len() < SIZE {
+        if next() % 4096 == 0 {
+            memory.push_str(&format!("mul({},{})", next() % 1000, next() % 1000));
+        } else {
+            memory.push(JUNK[next() % JUNK.len()] as char);
+        }
+    }
+    memory
+}
+
+/// How many operation names the old scanner found, searching for each name
+/// from the current position on every step.
+fn rescanning(mut input: &str) -> usize {
+    let mut found = 0;
+    loop {
+        let next = ["mul", "do()", "don't()"]
+            .iter()
+            .filter_map(|op| input.find(op).map(|index| (index, op.len())))
+            .min();
+        match next {
+            Some((index, len)) => {
+                found += 1;
+                input = &input[index + len..];
+            }
+            None => return found,
+        }
+    }
+}
+
+fn scanner(criterion: &mut Criterion) {
+    let memory = sparse_memory();
+    let mut group = criterion.benchmark_group("day3/sparse");
+    group.sample_size(10);
+    group.bench_function("parse_input", |bencher| {
+        bencher.iter(|| day3::parse_input(black_box(&memory)))
+    });
+    group.bench_function("rescanning", |bencher| {
+        bencher.iter(|| rescanning(black_box(&memory)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, scanner);
+criterion_main!(benches);
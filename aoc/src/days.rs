@@ -0,0 +1,30 @@
+use aoc_core::{Part, Solution};
+
+/// Parses the raw puzzle input and solves the requested part.
+pub type Solver = fn(&str, Part) -> Result<String, String>;
+
+pub const DAYS: [(u8, Solver); 11] = [
+    (1, run::<day1::Day1>),
+    (2, run::<day2::Day2>),
+    (3, run::<day3::Day3>),
+    (4, run::<day4::Day4>),
+    (5, run::<day5::Day5>),
+    (6, run::<day6::Day6>),
+    (7, run::<day7::Day7>),
+    (8, run::<day8::Day8>),
+    (9, run::<day9::Day9>),
+    (10, run::<day10::Day10>),
+    (11, run::<day11::Day11>),
+];
+
+pub fn solver(day: u8) -> Option<Solver> {
+    DAYS.iter()
+        .find(|(number, _)| *number == day)
+        .map(|(_, solver)| *solver)
+}
+
+fn run<S: Solution>(input: &str, part: Part) -> Result<String, String> {
+    let parsed = S::parse(input).map_err(|e| e.to_string())?;
+    let answer = S::solve(&parsed, part).map_err(|e| e.to_string())?;
+    Ok(answer.to_string())
+}
@@ -0,0 +1,165 @@
+use clap::{Args, ValueEnum};
+use std::{
+    fs::{self, File},
+    io::{BufReader, BufWriter},
+    path::{Path, PathBuf},
+};
+
+use crate::default_input;
+use day1::Pairing;
+
+#[derive(Args)]
+pub struct LocationsArgs {
+    /// Path to the puzzle input, defaults to `day1/input`
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Solve even if the two lists end up with different lengths
+    #[arg(long)]
+    allow_mismatch: bool,
+    /// Sort on disk rather than in memory, for lists too large for RAM
+    #[arg(long, conflicts_with = "allow_mismatch")]
+    external: bool,
+    /// How many IDs per list to sort in memory at a time with `--external`
+    #[arg(long, default_value_t = 1_000_000, requires = "external")]
+    run_size: usize,
+    /// List every sorted pair with its distance and a running total, followed
+    /// by the largest gaps
+    #[arg(long, conflicts_with = "external")]
+    report: bool,
+    /// How many of the largest gaps to list with `--report`
+    #[arg(long, default_value_t = 10, requires = "report")]
+    top: usize,
+    /// Write every sorted pair with its distance and running total to this
+    /// file as CSV
+    #[arg(long, conflicts_with = "external")]
+    csv: Option<PathBuf>,
+    /// Read any number of columns and compare these two, numbered from 0,
+    /// rather than the puzzle's left and right lists
+    #[arg(
+        long,
+        value_name = "A,B",
+        value_parser = parse_column_pair,
+        conflicts_with_all = ["allow_mismatch", "external", "report", "csv"]
+    )]
+    columns: Option<(usize, usize)>,
+    /// How to measure the distance between the `--columns`
+    #[arg(long, value_enum, default_value_t = MetricArg::L1, requires = "columns")]
+    metric: MetricArg,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum MetricArg {
+    /// The sum of absolute differences, as in part one
+    L1,
+    /// The sum of squared differences
+    Squared,
+    /// The largest single difference
+    Max,
+}
+
+fn parse_column_pair(value: &str) -> Result<(usize, usize), String> {
+    let (a, b) = value
+        .split_once(',')
+        .ok_or("expected two column numbers separated by a comma")?;
+    let column = |n: &str| n.trim().parse::<usize>().map_err(|e| e.to_string());
+    Ok((column(a)?, column(b)?))
+}
+
+pub fn run(args: LocationsArgs) -> Result<(), String> {
+    let path = args
+        .input
+        .clone()
+        .unwrap_or_else(|| default_input(Path::new("."), 1));
+    let result = if let Some((a, b)) = args.columns {
+        compare_columns(&path, a, b, args.metric)
+    } else if args.external {
+        external(&path, args.run_size)
+    } else {
+        in_memory(&path, &args)
+    };
+    result.map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn in_memory(path: &Path, args: &LocationsArgs) -> Result<(), String> {
+    let input = fs::read_to_string(path).map_err(|e| format!("failed to read: {}", e))?;
+
+    let loaded = day1::load(&input);
+    for diagnostic in &loaded.diagnostics {
+        eprintln!("warning: {}: {}", path.display(), diagnostic);
+    }
+    println!(
+        "accepted {} of {} lines",
+        loaded.accepted,
+        loaded.accepted + loaded.diagnostics.len()
+    );
+
+    let (left, right) = loaded
+        .into_sorted(args.allow_mismatch)
+        .map_err(|e| e.to_string())?;
+
+    if args.report {
+        print_report(&left, &right, args.top);
+    }
+    if let Some(csv) = &args.csv {
+        let file =
+            File::create(csv).map_err(|e| format!("failed to create {}: {}", csv.display(), e))?;
+        day1::write_csv(day1::pairings(&left, &right), BufWriter::new(file))
+            .map_err(|e| format!("failed to write {}: {}", csv.display(), e))?;
+    }
+
+    let distance = day1::solve_part_one(&left, &right).map_err(|e| e.to_string())?;
+    let similarity = day1::solve_part_two(&left, &right).map_err(|e| e.to_string())?;
+    println!("day  1 part 1: {}", distance);
+    println!("day  1 part 2: {}", similarity);
+    Ok(())
+}
+
+fn print_pairings(pairings: impl IntoIterator<Item = Pairing>) {
+    println!(
+        "{:>8} {:>10} {:>10} {:>10} {:>12}",
+        "rank", "left", "right", "distance", "total"
+    );
+    for pairing in pairings {
+        println!(
+            "{:>8} {:>10} {:>10} {:>10} {:>12}",
+            pairing.rank, pairing.left, pairing.right, pairing.distance, pairing.running_total
+        );
+    }
+}
+
+fn print_report(left: &[i64], right: &[i64], top: usize) {
+    print_pairings(day1::pairings(left, right));
+    println!();
+    println!("largest gaps:");
+    print_pairings(day1::largest_gaps(left, right, top));
+    println!();
+}
+
+fn external(path: &Path, run_size: usize) -> Result<(), String> {
+    let file = File::open(path).map_err(|e| format!("failed to read: {}", e))?;
+    let totals =
+        day1::solve_streaming(BufReader::new(file), run_size).map_err(|e| e.to_string())?;
+
+    println!("read {} pairs", totals.pairs);
+    println!("day  1 part 1: {}", totals.distance);
+    println!("day  1 part 2: {}", totals.similarity);
+    Ok(())
+}
+
+fn compare_columns(path: &Path, a: usize, b: usize, metric: MetricArg) -> Result<(), String> {
+    let input = fs::read_to_string(path).map_err(|e| format!("failed to read: {}", e))?;
+    let columns = day1::Columns::parse(&input).map_err(|e| e.to_string())?;
+
+    let metric: &dyn day1::Metric = match metric {
+        MetricArg::L1 => &day1::L1,
+        MetricArg::Squared => &day1::Squared,
+        MetricArg::Max => &day1::Max,
+    };
+    let distance = columns.distance(a, b, metric).map_err(|e| e.to_string())?;
+    let similarity = columns.similarity(a, b).map_err(|e| e.to_string())?;
+
+    println!("read {} columns", columns.len());
+    println!("distance between columns {} and {}: {}", a, b, distance);
+    println!("similarity of column {} to column {}: {}", a, b, similarity);
+    Ok(())
+}
@@ -0,0 +1,152 @@
+mod days;
+mod locations;
+mod memory;
+mod reports;
+
+use aoc_core::Part;
+use clap::{Parser, Subcommand};
+use days::Solver;
+use locations::LocationsArgs;
+use memory::MemoryArgs;
+use reports::ReportsArgs;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Instant,
+};
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code solutions in this workspace")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day
+    Run {
+        day: u8,
+        /// Only run this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Path to the puzzle input, defaults to `day<N>/input`
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Run every day that has an input file
+    All {
+        /// Directory containing the `day<N>` folders
+        #[arg(long, default_value = ".")]
+        root: PathBuf,
+    },
+    /// Check the day 1 location lists line by line, then solve with whatever
+    /// could be read
+    Locations(LocationsArgs),
+    /// Check the day 2 reports, optionally explaining every verdict
+    Reports(ReportsArgs),
+    /// Solve day 3, optionally highlighting every instruction in the memory
+    Memory(MemoryArgs),
+    /// Count the day 11 stones after any number of blinks
+    Stones {
+        /// How many times to blink
+        #[arg(long, default_value_t = 75)]
+        blinks: usize,
+        /// Path to the puzzle input, defaults to `day11/input`
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+}
+
+fn default_input(root: &Path, day: u8) -> PathBuf {
+    root.join(format!("day{}", day)).join("input")
+}
+
+fn run_parts(day: u8, solver: Solver, input: &str, parts: &[Part]) -> Result<(), String> {
+    for &part in parts {
+        let start = Instant::now();
+        let answer = solver(input, part)?;
+        println!(
+            "day {:>2} {}: {:<20} ({:.2?})",
+            day,
+            part,
+            answer,
+            start.elapsed()
+        );
+    }
+    Ok(())
+}
+
+fn run(day: u8, part: Option<u8>, input: Option<PathBuf>) -> Result<(), String> {
+    let solver = days::solver(day).ok_or_else(|| format!("no solution for day {}", day))?;
+    let path = input.unwrap_or_else(|| default_input(Path::new("."), day));
+    let input = fs::read_to_string(&path)
+        .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+
+    let parts = match part {
+        Some(1) => &Part::BOTH[..1],
+        Some(_) => &Part::BOTH[1..],
+        None => &Part::BOTH[..],
+    };
+    run_parts(day, solver, &input, parts).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn run_all(root: &Path) -> Result<(), String> {
+    let mut failed = 0;
+    for (day, solver) in days::DAYS {
+        let path = default_input(root, day);
+        match fs::read_to_string(&path) {
+            Ok(input) => {
+                if let Err(e) = run_parts(day, solver, &input, &Part::BOTH) {
+                    eprintln!("day {:>2}: {}: {}", day, path.display(), e);
+                    failed += 1;
+                }
+            }
+            Err(_) => println!("day {:>2}: skipped, no input at {}", day, path.display()),
+        }
+    }
+
+    if failed > 0 {
+        return Err(format!("{} day(s) failed", failed));
+    }
+    Ok(())
+}
+
+fn stones(blinks: usize, input: Option<PathBuf>) -> Result<(), String> {
+    let path = input.unwrap_or_else(|| default_input(Path::new("."), 11));
+    let input = fs::read_to_string(&path)
+        .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+    let stones = day11::parse_input(&input).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    let start = Instant::now();
+    let count = day11::count_stones(&stones, blinks);
+    println!(
+        "day 11 after {} blinks: {} ({:.2?})",
+        blinks,
+        count,
+        start.elapsed()
+    );
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run { day, part, input } => run(day, part, input),
+        Command::All { root } => run_all(&root),
+        Command::Locations(args) => locations::run(args),
+        Command::Reports(args) => reports::run(args),
+        Command::Memory(args) => memory::run(args),
+        Command::Stones { blinks, input } => stones(blinks, input),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
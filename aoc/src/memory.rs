@@ -0,0 +1,68 @@
+use clap::Args;
+use std::{
+    fs::{self, File},
+    io,
+    path::{Path, PathBuf},
+};
+
+use crate::default_input;
+
+#[derive(Args)]
+pub struct MemoryArgs {
+    /// Path to the puzzle input, defaults to `day3/input`
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Print the memory with the multiplications part two counts, the ones it
+    /// skips and the `do()`/`don't()` instructions highlighted
+    #[arg(long)]
+    annotate: bool,
+    /// Read the memory a chunk at a time rather than all at once, so it can be
+    /// any size and needn't be UTF-8; `--input -` reads it from standard input
+    #[arg(long, conflicts_with = "annotate")]
+    stream: bool,
+}
+
+pub fn run(args: MemoryArgs) -> Result<(), String> {
+    let path = args
+        .input
+        .unwrap_or_else(|| default_input(Path::new("."), 3));
+    if args.stream {
+        return stream(&path);
+    }
+
+    let input = fs::read_to_string(&path)
+        .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+    let instructions =
+        day3::parse_input(&input).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    if args.annotate {
+        // The legend goes through the same highlighting as the memory itself
+        let legend = "legend: mul(1,1) is counted, after don't() mul(1,1) is skipped until do()";
+        let parsed = day3::parse_input(legend).expect("the legend parses");
+        println!("{}", day3::annotate(legend, &parsed));
+        println!();
+        print!("{}", day3::annotate(&input, &instructions));
+        if !input.ends_with('\n') {
+            println!();
+        }
+        println!();
+    }
+
+    println!("found {} instructions", instructions.len());
+    println!("day  3 part 1: {}", day3::solve_part_one(&instructions));
+    println!("day  3 part 2: {}", day3::solve_part_two(&instructions));
+    Ok(())
+}
+
+fn stream(path: &Path) -> Result<(), String> {
+    let totals = if path == Path::new("-") {
+        day3::solve_stream(io::stdin().lock())
+    } else {
+        File::open(path).and_then(day3::solve_stream)
+    }
+    .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+
+    println!("day  3 part 1: {}", totals.part_one);
+    println!("day  3 part 2: {}", totals.part_two);
+    Ok(())
+}
@@ -0,0 +1,209 @@
+use clap::{Args, ValueEnum};
+use day2::{Costs, Direction, SafetyPolicy, Verdict};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::default_input;
+
+#[derive(Args)]
+pub struct ReportsArgs {
+    /// Path to the puzzle input, defaults to `day2/input`
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// List every report with its verdict: the pair that made it unsafe, and
+    /// which levels the dampener removed
+    #[arg(long)]
+    explain: bool,
+    /// Judge the reports by the safety policy in this TOML file rather than
+    /// the puzzle's rules; fields left out keep their part two values
+    #[arg(long)]
+    policy: Option<PathBuf>,
+    /// The smallest change allowed between adjacent levels
+    #[arg(long)]
+    min_step: Option<u32>,
+    /// The largest change allowed between adjacent levels
+    #[arg(long)]
+    max_step: Option<u32>,
+    /// Which way the levels may head
+    #[arg(long, value_enum)]
+    direction: Option<DirectionArg>,
+    /// How many levels the dampener may remove
+    #[arg(long)]
+    max_removals: Option<usize>,
+    /// List the longest safe subsequence of every report, with the indices of
+    /// the levels it keeps
+    #[arg(long)]
+    longest: bool,
+    /// Count how many reports need each number of removals to be safe
+    #[arg(long)]
+    histogram: bool,
+    /// Suggest the cheapest repair for every unsafe report, changing level
+    /// values and, with `--delete-cost`, removing levels
+    #[arg(long)]
+    repair: bool,
+    /// What changing the value of a level costs in a repair
+    #[arg(long, default_value_t = 1, requires = "repair")]
+    edit_cost: u64,
+    /// What removing a level costs in a repair, which is not allowed without
+    /// this
+    #[arg(long, requires = "repair")]
+    delete_cost: Option<u64>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum DirectionArg {
+    Increasing,
+    Decreasing,
+    Either,
+}
+
+impl ReportsArgs {
+    /// The policy from `--policy` with any other flags applied on top, or
+    /// `None` if no policy was asked for. Either way, anything left unset
+    /// keeps its value from the part two rules.
+    fn policy(&self) -> Result<Option<SafetyPolicy>, String> {
+        let overridden = self.min_step.is_some()
+            || self.max_step.is_some()
+            || self.direction.is_some()
+            || self.max_removals.is_some();
+        let mut policy = match &self.policy {
+            Some(path) => {
+                let toml = fs::read_to_string(path)
+                    .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+                SafetyPolicy::from_toml(&toml).map_err(|e| format!("{}: {}", path.display(), e))?
+            }
+            None if overridden => SafetyPolicy::default(),
+            None => return Ok(None),
+        };
+
+        if let Some(min_step) = self.min_step {
+            policy.min_step = min_step;
+        }
+        if let Some(max_step) = self.max_step {
+            policy.max_step = max_step;
+        }
+        if let Some(direction) = self.direction {
+            policy.direction = match direction {
+                DirectionArg::Increasing => Direction::Increasing,
+                DirectionArg::Decreasing => Direction::Decreasing,
+                DirectionArg::Either => Direction::Either,
+            };
+        }
+        if let Some(max_removals) = self.max_removals {
+            policy.max_removals = max_removals;
+        }
+        policy.validate().map(Some).map_err(|e| e.to_string())
+    }
+}
+
+pub fn run(args: ReportsArgs) -> Result<(), String> {
+    let policy = args.policy()?;
+    let path = args
+        .input
+        .unwrap_or_else(|| default_input(Path::new("."), 2));
+    let input = fs::read_to_string(&path)
+        .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+    let reports = day2::parse_lines(&input).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    let judged_by = policy.unwrap_or(SafetyPolicy::PART_TWO);
+    let verdicts: Vec<Verdict> = reports
+        .iter()
+        .map(|levels| day2::judge(levels, &judged_by))
+        .collect();
+    if args.explain {
+        for (index, (levels, verdict)) in reports.iter().zip(&verdicts).enumerate() {
+            println!("line {}: {}: {}", index + 1, show(levels), verdict);
+        }
+        println!();
+    }
+
+    if args.longest {
+        print_longest(&reports, &judged_by);
+    }
+    if args.histogram {
+        print_histogram(&reports, &judged_by);
+    }
+    if args.repair {
+        let costs = Costs {
+            edit: Some(args.edit_cost),
+            delete: args.delete_cost,
+        };
+        print_repairs(&reports, &judged_by, &costs);
+    }
+
+    let safe = verdicts.iter().filter(|verdict| verdict.is_safe()).count();
+    match policy {
+        Some(policy) => {
+            println!("policy: {}", policy);
+            println!("{} of {} reports are safe", safe, reports.len());
+        }
+        None => {
+            let undampened = verdicts
+                .iter()
+                .filter(|verdict| **verdict == Verdict::Safe)
+                .count();
+            println!("day  2 part 1: {}", undampened);
+            println!("day  2 part 2: {}", safe);
+        }
+    }
+    Ok(())
+}
+
+fn show<T: ToString>(items: &[T]) -> String {
+    let items: Vec<String> = items.iter().map(T::to_string).collect();
+    items.join(" ")
+}
+
+fn print_longest(reports: &[Vec<i32>], policy: &SafetyPolicy) {
+    for (index, levels) in reports.iter().enumerate() {
+        let kept = day2::longest_safe(levels, policy);
+        let subsequence: Vec<i32> = kept.iter().map(|&index| levels[index]).collect();
+        println!(
+            "line {}: {} of {} levels: {} (indices {})",
+            index + 1,
+            kept.len(),
+            levels.len(),
+            show(&subsequence),
+            show(&kept)
+        );
+    }
+    println!();
+}
+
+fn print_histogram(reports: &[Vec<i32>], policy: &SafetyPolicy) {
+    println!("{:>8} {:>8}", "removals", "reports");
+    for (removals, count) in day2::removal_histogram(reports, policy)
+        .into_iter()
+        .enumerate()
+    {
+        println!("{:>8} {:>8}", removals, count);
+    }
+    println!();
+}
+
+fn print_repairs(reports: &[Vec<i32>], policy: &SafetyPolicy, costs: &Costs) {
+    let (mut repaired, mut total) = (0, 0);
+    for (index, levels) in reports.iter().enumerate() {
+        match day2::repair(levels, policy, costs) {
+            Some(repair) if repair.cost == 0 => {}
+            Some(repair) => {
+                println!(
+                    "line {}: {} -> {} (cost {}, {} edited, {} removed)",
+                    index + 1,
+                    show(levels),
+                    show(&repair.levels),
+                    repair.cost,
+                    repair.edited.len(),
+                    repair.deleted.len()
+                );
+                repaired += 1;
+                total += repair.cost;
+            }
+            None => println!("line {}: {}: no repair possible", index + 1, show(levels)),
+        }
+    }
+    println!("repaired {} reports at a total cost of {}", repaired, total);
+    println!();
+}
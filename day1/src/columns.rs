@@ -0,0 +1,150 @@
+use nom::{character::complete::space1, multi::separated_list1, IResult};
+use parsing::{integer, lines, parse_all};
+
+use crate::{metric::Metric, Error};
+
+fn parse_row(input: &str) -> IResult<&str, Vec<i64>> {
+    separated_list1(space1, integer)(input)
+}
+
+/// Any number of whitespace-separated columns of location IDs, each sorted.
+///
+/// Columns are numbered from 0, so the puzzle's left and right lists are
+/// columns 0 and 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Columns {
+    columns: Vec<Vec<i64>>,
+}
+
+impl Columns {
+    /// Reads one row of IDs per line. Every row must have as many IDs as the
+    /// first.
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        let rows = parse_all(lines(parse_row), input)?;
+
+        let width = rows[0].len();
+        if let Some(index) = rows.iter().position(|row| row.len() != width) {
+            return Err(Error::RaggedRow {
+                line: index + 1,
+                expected: width,
+                found: rows[index].len(),
+            });
+        }
+
+        let mut columns = vec![Vec::with_capacity(rows.len()); width];
+        for row in rows {
+            for (column, id) in columns.iter_mut().zip(row) {
+                column.push(id);
+            }
+        }
+        for column in &mut columns {
+            column.sort();
+        }
+        Ok(Columns { columns })
+    }
+
+    /// How many columns there are.
+    pub fn len(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Never true, since an input with no columns does not parse.
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+
+    /// The IDs in column `index`, sorted.
+    pub fn column(&self, index: usize) -> Result<&[i64], Error> {
+        self.columns
+            .get(index)
+            .map(Vec::as_slice)
+            .ok_or(Error::NoSuchColumn {
+                column: index,
+                columns: self.columns.len(),
+            })
+    }
+
+    /// The distance between columns `a` and `b` under `metric`, pairing up
+    /// their IDs smallest first as part one does.
+    pub fn distance(&self, a: usize, b: usize, metric: &dyn Metric) -> Result<u128, Error> {
+        let (a, b) = (self.column(a)?, self.column(b)?);
+        a.iter().zip(b).try_fold(0, |total, (&a, &b)| {
+            metric
+                .combine(total, metric.distance(a, b))
+                .ok_or(Error::Overflow)
+        })
+    }
+
+    /// The part two similarity score of column `a` against column `b`.
+    pub fn similarity(&self, a: usize, b: usize) -> Result<i64, Error> {
+        crate::solve_part_two(self.column(a)?, self.column(b)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Max, Squared, EXAMPLE, L1};
+
+    const THREE: &str = "3   4   1\n4   3   1\n2   5   9\n1   3   4\n3   9   2\n3   3   3";
+
+    #[test]
+    fn two_columns_match_the_puzzle() {
+        let columns = Columns::parse(EXAMPLE).unwrap();
+        assert_eq!(columns.len(), 2);
+        assert_eq!(columns.distance(0, 1, &L1).unwrap(), 11);
+        assert_eq!(columns.similarity(0, 1).unwrap(), 31);
+    }
+
+    #[test]
+    fn any_pair_of_columns() {
+        let columns = Columns::parse(THREE).unwrap();
+        assert_eq!(columns.column(2).unwrap(), &[1, 1, 2, 3, 4, 9]);
+        // 1 2 3 3 3 4 against 1 1 2 3 4 9
+        assert_eq!(columns.distance(0, 2, &L1).unwrap(), 8);
+        assert_eq!(columns.distance(0, 2, &Squared).unwrap(), 28);
+        assert_eq!(columns.distance(0, 2, &Max).unwrap(), 5);
+        assert_eq!(columns.distance(2, 0, &L1).unwrap(), 8);
+        assert_eq!(columns.similarity(0, 2).unwrap(), 2 + 2 + 3 * 3 + 4);
+        assert_eq!(columns.similarity(2, 0).unwrap(), 1 + 1 + 2 + 3 * 3 + 4);
+    }
+
+    #[test]
+    fn metrics_can_overflow() {
+        let columns = Columns::parse(
+            "-9223372036854775808   9223372036854775807
+-9223372036854775808   9223372036854775807",
+        )
+        .unwrap();
+        assert_eq!(columns.distance(0, 1, &L1).unwrap(), 2 * u64::MAX as u128);
+        assert!(matches!(
+            columns.distance(0, 1, &Squared),
+            Err(Error::Overflow)
+        ));
+    }
+
+    #[test]
+    fn missing_columns() {
+        let columns = Columns::parse(THREE).unwrap();
+        assert!(matches!(
+            columns.distance(0, 3, &L1),
+            Err(Error::NoSuchColumn {
+                column: 3,
+                columns: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        let result = Columns::parse("1 2 3\n4 5 6\n7 8\n");
+        assert!(matches!(
+            result,
+            Err(Error::RaggedRow {
+                line: 3,
+                expected: 3,
+                found: 2
+            })
+        ));
+    }
+}
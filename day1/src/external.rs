@@ -0,0 +1,299 @@
+use nom::{character::complete::space0, sequence::preceded};
+use parsing::parse_all;
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::{parse_two_numbers, Error};
+
+/// The most runs merged at once, which keeps the number of open files down.
+const MAX_FAN_IN: usize = 64;
+
+/// Both answers from a streaming solve. They are `i128` since with billions of
+/// pairs they can outgrow an `i64`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Totals {
+    /// How many pairs were read.
+    pub pairs: u64,
+    pub distance: i128,
+    pub similarity: i128,
+}
+
+/// One column of IDs, sorted in runs of at most `run_size` that are written out
+/// to files in `dir` as they fill up.
+struct Runs<'a> {
+    dir: &'a Path,
+    name: &'static str,
+    run_size: usize,
+    buffer: Vec<i64>,
+    files: Vec<PathBuf>,
+    written: usize,
+}
+
+impl<'a> Runs<'a> {
+    fn new(dir: &'a Path, name: &'static str, run_size: usize) -> Self {
+        Runs {
+            dir,
+            name,
+            run_size: run_size.max(1),
+            buffer: Vec::new(),
+            files: Vec::new(),
+            written: 0,
+        }
+    }
+
+    fn next_file(&mut self) -> PathBuf {
+        self.written += 1;
+        self.dir.join(format!("{}-{}", self.name, self.written))
+    }
+
+    fn push(&mut self, id: i64) -> io::Result<()> {
+        self.buffer.push(id);
+        if self.buffer.len() >= self.run_size {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+
+        self.buffer.sort_unstable();
+        let path = self.next_file();
+        let mut writer = BufWriter::new(File::create(&path)?);
+        for id in self.buffer.drain(..) {
+            writer.write_all(&id.to_le_bytes())?;
+        }
+        writer.flush()?;
+        self.files.push(path);
+        Ok(())
+    }
+
+    /// Writes out what is left in the buffer, then merges runs together until
+    /// there are few enough to merge in one go.
+    fn finish(mut self) -> io::Result<Vec<PathBuf>> {
+        self.flush()?;
+        while self.files.len() > MAX_FAN_IN {
+            let files = std::mem::take(&mut self.files);
+            for group in files.chunks(MAX_FAN_IN) {
+                let path = self.next_file();
+                let mut merged = Merged::open(group)?;
+                let mut writer = BufWriter::new(File::create(&path)?);
+                while let Some(id) = merged.next_id()? {
+                    writer.write_all(&id.to_le_bytes())?;
+                }
+                writer.flush()?;
+                for file in group {
+                    fs::remove_file(file)?;
+                }
+                self.files.push(path);
+            }
+        }
+        Ok(self.files)
+    }
+}
+
+fn read_id(reader: &mut impl Read) -> io::Result<Option<i64>> {
+    let mut bytes = [0; 8];
+    match reader.read_exact(&mut bytes) {
+        Ok(()) => Ok(Some(i64::from_le_bytes(bytes))),
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// A k-way merge over sorted run files, yielding their IDs in order.
+struct Merged {
+    readers: Vec<BufReader<File>>,
+    heap: BinaryHeap<Reverse<(i64, usize)>>,
+    peeked: Option<i64>,
+}
+
+impl Merged {
+    fn open(files: &[PathBuf]) -> io::Result<Self> {
+        let mut readers = Vec::with_capacity(files.len());
+        let mut heap = BinaryHeap::with_capacity(files.len());
+        for (index, file) in files.iter().enumerate() {
+            let mut reader = BufReader::new(File::open(file)?);
+            if let Some(id) = read_id(&mut reader)? {
+                heap.push(Reverse((id, index)));
+            }
+            readers.push(reader);
+        }
+
+        Ok(Merged {
+            readers,
+            heap,
+            peeked: None,
+        })
+    }
+
+    fn next_id(&mut self) -> io::Result<Option<i64>> {
+        if let Some(id) = self.peeked.take() {
+            return Ok(Some(id));
+        }
+
+        let Some(Reverse((id, index))) = self.heap.pop() else {
+            return Ok(None);
+        };
+        if let Some(next) = read_id(&mut self.readers[index])? {
+            self.heap.push(Reverse((next, index)));
+        }
+        Ok(Some(id))
+    }
+
+    /// The next distinct ID along with how many times it appears.
+    fn next_group(&mut self) -> io::Result<Option<(i64, u64)>> {
+        let Some(id) = self.next_id()? else {
+            return Ok(None);
+        };
+
+        let mut count = 1;
+        while let Some(next) = self.next_id()? {
+            if next != id {
+                self.peeked = Some(next);
+                break;
+            }
+            count += 1;
+        }
+        Ok(Some((id, count)))
+    }
+}
+
+/// Solves both parts without ever holding the lists in memory.
+///
+/// Each column is sorted in runs of `run_size` IDs that go out to temporary
+/// files, and the runs are then merged back together: pairwise for the total
+/// distance, and as a join on equal IDs for the similarity score. Fails with
+/// [`Error::Overflow`] if even an `i128` is not enough.
+pub fn solve_streaming(mut input: impl BufRead, run_size: usize) -> Result<Totals, Error> {
+    let dir = tempfile::tempdir()?;
+    let mut left = Runs::new(dir.path(), "left", run_size);
+    let mut right = Runs::new(dir.path(), "right", run_size);
+
+    let mut pairs = 0;
+    let mut line = String::new();
+    let mut number = 0;
+    let mut offset = 0;
+    loop {
+        line.clear();
+        let read = input.read_line(&mut line)?;
+        if read == 0 {
+            break;
+        }
+        number += 1;
+
+        let text = line.trim_end_matches(['\n', '\r']);
+        if !text.trim().is_empty() {
+            let (a, b) = parse_all(preceded(space0, parse_two_numbers), text)
+                .map_err(|e| e.on_line(number, offset))?;
+            left.push(a)?;
+            right.push(b)?;
+            pairs += 1;
+        }
+        offset += read;
+    }
+
+    let left = left.finish()?;
+    let right = right.finish()?;
+
+    let mut distance = 0;
+    let (mut lefts, mut rights) = (Merged::open(&left)?, Merged::open(&right)?);
+    while let (Some(a), Some(b)) = (lefts.next_id()?, rights.next_id()?) {
+        distance = (a.abs_diff(b) as i128)
+            .checked_add(distance)
+            .ok_or(Error::Overflow)?;
+    }
+
+    let mut similarity = 0;
+    let (mut lefts, mut rights) = (Merged::open(&left)?, Merged::open(&right)?);
+    let (mut a, mut b) = (lefts.next_group()?, rights.next_group()?);
+    while let (Some((id, left_count)), Some((other, right_count))) = (a, b) {
+        match id.cmp(&other) {
+            Ordering::Less => a = lefts.next_group()?,
+            Ordering::Greater => b = rights.next_group()?,
+            Ordering::Equal => {
+                similarity = (id as i128)
+                    .checked_mul(left_count as i128)
+                    .and_then(|score| score.checked_mul(right_count as i128))
+                    .and_then(|score| score.checked_add(similarity))
+                    .ok_or(Error::Overflow)?;
+                a = lefts.next_group()?;
+                b = rights.next_group()?;
+            }
+        }
+    }
+
+    Ok(Totals {
+        pairs,
+        distance,
+        similarity,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let totals = solve_streaming(crate::EXAMPLE.as_bytes(), 2).unwrap();
+        assert_eq!(
+            totals,
+            Totals {
+                pairs: 6,
+                distance: 11,
+                similarity: 31
+            }
+        );
+    }
+
+    #[test]
+    fn matches_in_memory_solve_across_merge_passes() {
+        // Enough single-ID runs to need more than one round of merging
+        let mut seed: u32 = 7;
+        let mut input = String::new();
+        for _ in 0..300 {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            let a = (seed >> 16) % 50;
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            let b = (seed >> 16) % 50;
+            input.push_str(&format!("{}   {}\r\n", a, b));
+        }
+
+        let (left, right) = crate::parse_input(&input).unwrap();
+        let totals = solve_streaming(input.as_bytes(), 1).unwrap();
+        assert_eq!(totals.pairs, 300);
+        assert_eq!(
+            totals.distance,
+            crate::solve_part_one(&left, &right).unwrap() as i128
+        );
+        assert_eq!(
+            totals.similarity,
+            crate::solve_part_two(&left, &right).unwrap() as i128
+        );
+    }
+
+    #[test]
+    fn extreme_ids() {
+        let input = "-9223372036854775808   9223372036854775807\n9223372036854775807   9223372036854775807\n";
+        let totals = solve_streaming(input.as_bytes(), 1).unwrap();
+        assert_eq!(totals.distance, u64::MAX as i128);
+        assert_eq!(totals.similarity, 2 * i64::MAX as i128);
+    }
+
+    #[test]
+    fn reports_the_bad_line() {
+        let error = solve_streaming("3   4\n\n4   x\n".as_bytes(), 2).unwrap_err();
+        let Error::Parse(e) = error else {
+            panic!("expected a parse error, got {:?}", error);
+        };
+        assert_eq!((e.line, e.column, e.offset), (3, 5, 11));
+    }
+}
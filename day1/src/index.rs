@@ -0,0 +1,427 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::Column;
+
+/// The lowest and highest IDs, as bounds of the range `Balance` covers.
+const LOWEST: i128 = i64::MIN as i128;
+const HIGHEST: i128 = i64::MAX as i128;
+
+#[derive(Debug, Clone, Copy)]
+struct Node {
+    min: i128,
+    max: i128,
+    /// The sum of `|D(x)|` over the node's range.
+    total: i128,
+    /// Changes to `D` and to `|D|` still to be passed down to the children.
+    add: i128,
+    abs_add: i128,
+    children: Option<(usize, usize)>,
+}
+
+impl Node {
+    fn uniform(value: i128, len: i128) -> Self {
+        Node {
+            min: value,
+            max: value,
+            total: value.abs() * len,
+            add: 0,
+            abs_add: 0,
+            children: None,
+        }
+    }
+}
+
+/// `D(x) = #{left ≤ x} - #{right ≤ x}` for every possible ID `x`, in a segment
+/// tree over the whole `i64` range that only splits where it has to.
+///
+/// When both lists are the same length, the sum of `|D(x)|` over every `x` is
+/// exactly the total distance between the sorted lists: it is the area between
+/// their two step functions.
+#[derive(Debug, Clone)]
+struct Balance {
+    nodes: Vec<Node>,
+}
+
+impl Balance {
+    fn new() -> Self {
+        Balance {
+            nodes: vec![Node::uniform(0, HIGHEST - LOWEST + 1)],
+        }
+    }
+
+    /// The balance for lists with `steps` already applied, each a distinct ID
+    /// and what `D` changes by there, sorted by ID.
+    ///
+    /// Builds the tree in one go rather than one step at a time, which can take
+    /// O(n) per step when the lists interleave.
+    fn from_steps(steps: &[(i64, i128)]) -> Self {
+        let mut balance = Balance { nodes: Vec::new() };
+        balance.build(LOWEST, HIGHEST, steps, 0);
+        balance
+    }
+
+    /// Builds the node covering `low..=high`, where `D` is `before` at `low`
+    /// apart from `steps`, returning its index.
+    fn build(&mut self, low: i128, high: i128, steps: &[(i64, i128)], before: i128) -> usize {
+        let index = self.nodes.len();
+        let len = high - low + 1;
+        match steps {
+            [] => self.nodes.push(Node::uniform(before, len)),
+            [(id, delta)] if *id as i128 == low => {
+                self.nodes.push(Node::uniform(before + delta, len))
+            }
+            _ => {
+                self.nodes.push(Node::uniform(before, len));
+                let middle = low + (high - low) / 2;
+                let split = steps.partition_point(|&(id, _)| id as i128 <= middle);
+                let (lower, upper) = steps.split_at(split);
+                let after: i128 = before + lower.iter().map(|(_, delta)| delta).sum::<i128>();
+
+                let left = self.build(low, middle, lower, before);
+                let right = self.build(middle + 1, high, upper, after);
+                let (left_node, right_node) = (self.nodes[left], self.nodes[right]);
+                let node = &mut self.nodes[index];
+                node.min = left_node.min.min(right_node.min);
+                node.max = left_node.max.max(right_node.max);
+                node.total = left_node.total + right_node.total;
+                node.children = Some((left, right));
+            }
+        }
+        index
+    }
+
+    fn total(&self) -> i128 {
+        self.nodes[0].total
+    }
+
+    /// Adds `delta`, which is 1 or -1, to `D(x)` for every `x` from `from` up.
+    fn add_from(&mut self, from: i64, delta: i128) {
+        self.add(0, LOWEST, HIGHEST, from as i128, delta);
+    }
+
+    fn apply(&mut self, index: usize, len: i128, add: i128, abs_add: i128) {
+        let node = &mut self.nodes[index];
+        node.min += add;
+        node.max += add;
+        node.total += abs_add * len;
+        if node.children.is_some() {
+            node.add += add;
+            node.abs_add += abs_add;
+        }
+    }
+
+    fn children(&mut self, index: usize, low: i128, high: i128) -> (usize, usize) {
+        let middle = low + (high - low) / 2;
+        let node = self.nodes[index];
+        match node.children {
+            Some(children) => {
+                if node.add != 0 || node.abs_add != 0 {
+                    self.apply(children.0, middle - low + 1, node.add, node.abs_add);
+                    self.apply(children.1, high - middle, node.add, node.abs_add);
+                    self.nodes[index].add = 0;
+                    self.nodes[index].abs_add = 0;
+                }
+                children
+            }
+            None => {
+                // Until now every x in the node had the same value
+                self.nodes.push(Node::uniform(node.min, middle - low + 1));
+                self.nodes.push(Node::uniform(node.min, high - middle));
+                let children = (self.nodes.len() - 2, self.nodes.len() - 1);
+                self.nodes[index].children = Some(children);
+                children
+            }
+        }
+    }
+
+    fn add(&mut self, index: usize, low: i128, high: i128, from: i128, delta: i128) {
+        if high < from {
+            return;
+        }
+
+        let len = high - low + 1;
+        if from <= low {
+            // If every value in the node moves the same way relative to zero,
+            // every |D(x)| changes by the same amount and there is no need to
+            // look any further down.
+            let Node { min, max, .. } = self.nodes[index];
+            let away_from_zero = if delta > 0 { min >= 0 } else { max <= 0 };
+            let towards_zero = if delta > 0 { max < 0 } else { min > 0 };
+            if away_from_zero || towards_zero {
+                let abs_add = if away_from_zero { 1 } else { -1 };
+                self.apply(index, len, delta, abs_add);
+                return;
+            }
+        }
+
+        let middle = low + (high - low) / 2;
+        let (left, right) = self.children(index, low, high);
+        self.add(left, low, middle, from, delta);
+        self.add(right, middle + 1, high, from, delta);
+
+        let (left, right) = (self.nodes[left], self.nodes[right]);
+        let node = &mut self.nodes[index];
+        node.min = left.min.min(right.min);
+        node.max = left.max.max(right.max);
+        node.total = left.total + right.total;
+    }
+}
+
+/// Both location lists, kept up to date one insertion or removal at a time
+/// along with their similarity score and total distance.
+///
+/// The similarity score changes by `id * count` of that ID in the other list,
+/// so it takes O(1) to update. The total distance is harder: one new ID shifts
+/// which IDs pair up after it, so it is kept as the area between the two
+/// lists' step functions instead. Updating that takes O(log U) for each stretch
+/// of IDs above the changed one where the lists swap which is ahead, U being
+/// the range of an `i64`. That is O(log U) when the lists barely interleave
+/// but O(n log U) at worst, so this does not meet an O(log n) bound; see the
+/// README. [`Index::from_lists`] builds everything at once in O(n log U).
+#[derive(Debug, Clone)]
+pub struct Index {
+    left: HashMap<i64, u64>,
+    right: HashMap<i64, u64>,
+    left_len: usize,
+    right_len: usize,
+    similarity: i128,
+    balance: Balance,
+}
+
+impl Default for Index {
+    fn default() -> Self {
+        Index::new()
+    }
+}
+
+impl Index {
+    pub fn new() -> Self {
+        Index {
+            left: HashMap::new(),
+            right: HashMap::new(),
+            left_len: 0,
+            right_len: 0,
+            similarity: 0,
+            balance: Balance::new(),
+        }
+    }
+
+    /// An index over two existing lists, which do not need to be sorted.
+    pub fn from_lists(left: &[i64], right: &[i64]) -> Self {
+        let mut index = Index::new();
+        for &id in left {
+            *index.left.entry(id).or_default() += 1;
+        }
+        for &id in right {
+            *index.right.entry(id).or_default() += 1;
+        }
+        index.left_len = left.len();
+        index.right_len = right.len();
+        index.similarity = index
+            .left
+            .iter()
+            .map(|(&id, &count)| {
+                id as i128 * count as i128 * index.count(Column::Right, id) as i128
+            })
+            .sum();
+
+        // What D changes by at each ID, netting out IDs in both lists
+        let mut steps: BTreeMap<i64, i128> = BTreeMap::new();
+        for (&id, &count) in &index.left {
+            *steps.entry(id).or_default() += count as i128;
+        }
+        for (&id, &count) in &index.right {
+            *steps.entry(id).or_default() -= count as i128;
+        }
+        let steps: Vec<(i64, i128)> = steps.into_iter().collect();
+        index.balance = Balance::from_steps(&steps);
+        index
+    }
+
+    fn count(&self, column: Column, id: i64) -> u64 {
+        let counts = match column {
+            Column::Left => &self.left,
+            Column::Right => &self.right,
+        };
+        counts.get(&id).copied().unwrap_or(0)
+    }
+
+    pub fn len(&self, column: Column) -> usize {
+        match column {
+            Column::Left => self.left_len,
+            Column::Right => self.right_len,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.left_len == 0 && self.right_len == 0
+    }
+
+    pub fn insert(&mut self, column: Column, id: i64) {
+        let other = match column {
+            Column::Left => Column::Right,
+            Column::Right => Column::Left,
+        };
+        self.similarity += id as i128 * self.count(other, id) as i128;
+
+        match column {
+            Column::Left => {
+                *self.left.entry(id).or_default() += 1;
+                self.left_len += 1;
+                self.balance.add_from(id, 1);
+            }
+            Column::Right => {
+                *self.right.entry(id).or_default() += 1;
+                self.right_len += 1;
+                self.balance.add_from(id, -1);
+            }
+        }
+    }
+
+    /// Removes one copy of `id` from `column`, returning whether it was there.
+    pub fn remove(&mut self, column: Column, id: i64) -> bool {
+        let (counts, len, other, delta) = match column {
+            Column::Left => (&mut self.left, &mut self.left_len, &self.right, -1),
+            Column::Right => (&mut self.right, &mut self.right_len, &self.left, 1),
+        };
+        let Some(count) = counts.get_mut(&id) else {
+            return false;
+        };
+
+        *count -= 1;
+        if *count == 0 {
+            counts.remove(&id);
+        }
+        *len -= 1;
+        self.similarity -= id as i128 * other.get(&id).copied().unwrap_or(0) as i128;
+        self.balance.add_from(id, delta);
+        true
+    }
+
+    /// The part two answer for the lists as they stand. This cannot overflow
+    /// an `i128` for lists of fewer than 2^32 IDs each.
+    pub fn similarity(&self) -> i128 {
+        self.similarity
+    }
+
+    /// The part one answer for the lists as they stand, or `None` while they
+    /// have different lengths and so do not pair up.
+    pub fn distance(&self) -> Option<i128> {
+        (self.left_len == self.right_len).then(|| self.balance.total())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let (left, right) = crate::parse_input(crate::EXAMPLE).unwrap();
+        let index = Index::from_lists(&left, &right);
+        assert_eq!(index.distance(), Some(11));
+        assert_eq!(index.similarity(), 31);
+    }
+
+    #[test]
+    fn uneven_lists_have_no_distance() {
+        let mut index = Index::new();
+        assert_eq!(index.distance(), Some(0));
+        index.insert(Column::Left, 5);
+        assert_eq!(index.distance(), None);
+        index.insert(Column::Right, -3);
+        assert_eq!(index.distance(), Some(8));
+        assert!(!index.remove(Column::Right, 5));
+        assert!(index.remove(Column::Left, 5));
+        assert_eq!(index.len(Column::Left), 0);
+        assert_eq!(index.len(Column::Right), 1);
+    }
+
+    #[test]
+    fn from_lists_matches_inserting_one_at_a_time() {
+        let left: Vec<i64> = (0..500).map(|id| id * 2).chain([7, 7, i64::MIN]).collect();
+        let right: Vec<i64> = (0..500)
+            .map(|id| id * 2 + 1)
+            .chain([7, i64::MAX, 0])
+            .collect();
+
+        let built = Index::from_lists(&left, &right);
+        let mut inserted = Index::new();
+        for (&l, &r) in left.iter().zip(&right) {
+            inserted.insert(Column::Left, l);
+            inserted.insert(Column::Right, r);
+        }
+        assert_eq!(built.distance(), inserted.distance());
+        assert_eq!(built.similarity(), inserted.similarity());
+
+        // Both go on being updated the same way
+        let (mut built, mut inserted) = (built, inserted);
+        for index in [&mut built, &mut inserted] {
+            index.remove(Column::Left, 7);
+            index.insert(Column::Left, 501);
+        }
+        assert_eq!(built.distance(), inserted.distance());
+        assert_eq!(built.similarity(), inserted.similarity());
+    }
+
+    #[test]
+    fn extreme_ids() {
+        let mut index = Index::new();
+        index.insert(Column::Left, i64::MIN);
+        index.insert(Column::Right, i64::MAX);
+        assert_eq!(index.distance(), Some(u64::MAX as i128));
+        index.insert(Column::Left, i64::MAX);
+        index.insert(Column::Right, i64::MIN);
+        assert_eq!(index.distance(), Some(0));
+        assert_eq!(index.similarity(), i64::MIN as i128 + i64::MAX as i128);
+    }
+
+    #[test]
+    fn matches_solving_from_scratch() {
+        let mut seed: u32 = 11;
+        let mut next = |modulus: u32| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            (seed >> 16) % modulus
+        };
+
+        let mut index = Index::new();
+        let (mut left, mut right) = (Vec::new(), Vec::new());
+        for _ in 0..2000 {
+            let column = if next(2) == 0 {
+                Column::Left
+            } else {
+                Column::Right
+            };
+            let list = match column {
+                Column::Left => &mut left,
+                Column::Right => &mut right,
+            };
+            let id = next(40) as i64 - 10;
+
+            if next(3) == 0 {
+                let position = list.iter().position(|&other| other == id);
+                assert_eq!(index.remove(column, id), position.is_some());
+                if let Some(position) = position {
+                    list.remove(position);
+                }
+            } else {
+                index.insert(column, id);
+                list.push(id);
+            }
+
+            let (mut sorted_left, mut sorted_right) = (left.clone(), right.clone());
+            sorted_left.sort();
+            sorted_right.sort();
+            assert_eq!(
+                index.similarity(),
+                crate::solve_part_two(&sorted_left, &sorted_right).unwrap() as i128
+            );
+            if left.len() == right.len() {
+                assert_eq!(
+                    index.distance(),
+                    Some(crate::solve_part_one(&sorted_left, &sorted_right).unwrap() as i128)
+                );
+            }
+        }
+    }
+}
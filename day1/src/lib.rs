@@ -0,0 +1,233 @@
+use aoc_core::Solution;
+use nom::{character::complete::space1, combinator::map, sequence::separated_pair, IResult};
+use parsing::{integer, lines, parse_all, ParseError};
+use std::{collections::HashMap, fmt, io};
+
+mod columns;
+mod external;
+mod index;
+mod load;
+mod metric;
+mod report;
+
+pub use columns::Columns;
+pub use external::{solve_streaming, Totals};
+pub use index::Index;
+pub use load::{load, Column, Diagnostic, Loaded, Reason};
+pub use metric::{Max, Metric, Squared, L1};
+pub use report::{largest_gaps, pairings, write_csv, Pairing};
+
+fn parse_two_numbers(input: &str) -> IResult<&str, (i64, i64)> {
+    separated_pair(integer, space1, integer)(input)
+}
+
+/// Splits the input into its left and right location lists, both sorted.
+pub fn parse_input(input: &str) -> Result<(Vec<i64>, Vec<i64>), ParseError> {
+    let lists = map(lines(parse_two_numbers), |pairs| {
+        let (mut left, mut right): (Vec<i64>, Vec<i64>) = pairs.into_iter().unzip();
+        left.sort();
+        right.sort();
+        (left, right)
+    });
+    parse_all(lists, input)
+}
+
+/// The worked example from the puzzle description.
+pub const EXAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3";
+
+/// The total distance between the sorted lists, or [`Error::Overflow`] if it
+/// does not fit in an `i64`.
+pub fn solve_part_one(left: &[i64], right: &[i64]) -> Result<i64, Error> {
+    left.iter().zip(right.iter()).try_fold(0i64, |acc, (a, b)| {
+        i64::try_from(a.abs_diff(*b))
+            .ok()
+            .and_then(|distance| acc.checked_add(distance))
+            .ok_or(Error::Overflow)
+    })
+}
+
+/// The similarity score of the two lists, or [`Error::Overflow`] if it does
+/// not fit in an `i64`.
+pub fn solve_part_two(left: &[i64], right: &[i64]) -> Result<i64, Error> {
+    let frequencies = right.iter().fold(HashMap::new(), |mut map, &num| {
+        *map.entry(num).or_insert(0i64) += 1;
+        map
+    });
+    left.iter().try_fold(0i64, |acc, num| {
+        frequencies
+            .get(num)
+            .unwrap_or(&0)
+            .checked_mul(*num)
+            .and_then(|score| acc.checked_add(score))
+            .ok_or(Error::Overflow)
+    })
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Parse(ParseError),
+    Io(io::Error),
+    MismatchedLengths {
+        left: usize,
+        right: usize,
+    },
+    /// An answer is too large for the integer type it is computed in.
+    Overflow,
+    /// This (1-based) line has a different number of IDs to the first.
+    RaggedRow {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// Column `column` was asked for but there are only `columns` of them.
+    NoSuchColumn {
+        column: usize,
+        columns: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(e) => write!(f, "{}", e),
+            Error::Io(e) => write!(f, "{}", e),
+            Error::MismatchedLengths { left, right } => write!(
+                f,
+                "the left list has {} location IDs but the right list has {}",
+                left, right
+            ),
+            Error::Overflow => write!(f, "the answer is too large to compute"),
+            Error::RaggedRow {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {}: expected {} location IDs but found {}",
+                line, expected, found
+            ),
+            Error::NoSuchColumn { column, columns } => write!(
+                f,
+                "there is no column {}, the input only has columns 0 to {}",
+                column,
+                columns - 1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = (Vec<i64>, Vec<i64>);
+    type Answer = i64;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one((left, right): &Self::Input) -> Result<Self::Answer, Self::Error> {
+        solve_part_one(left, right)
+    }
+
+    fn part_two((left, right): &Self::Input) -> Result<Self::Answer, Self::Error> {
+        solve_part_two(left, right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::error::ErrorKind;
+
+    #[test]
+    fn test_solutions() {
+        let (left, right) = parse_input(EXAMPLE).expect("failed to parse");
+        assert_eq!(solve_part_one(&left, &right).unwrap(), 11);
+        assert_eq!(solve_part_two(&left, &right).unwrap(), 31);
+    }
+
+    #[test]
+    fn ids_near_the_limits() {
+        let (left, right) = parse_input(
+            "9223372036854775807   9223372036854775806
+-9223372036854775808   -9223372036854775807",
+        )
+        .unwrap();
+        assert_eq!(solve_part_one(&left, &right).unwrap(), 2);
+        assert_eq!(solve_part_two(&left, &right).unwrap(), 0);
+
+        let (left, right) = parse_input("9223372036854775807   9223372036854775807").unwrap();
+        assert_eq!(solve_part_one(&left, &right).unwrap(), 0);
+        assert_eq!(solve_part_two(&left, &right).unwrap(), i64::MAX);
+
+        let error = parse_input("9223372036854775808   1").unwrap_err();
+        assert_eq!(error.kind, ErrorKind::MapRes);
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        let (left, right) = parse_input("-9223372036854775808   9223372036854775807").unwrap();
+        assert!(matches!(
+            solve_part_one(&left, &right),
+            Err(Error::Overflow)
+        ));
+
+        let (left, right) = parse_input(
+            "4611686018427387904   0
+4611686018427387904   4611686018427387904
+4611686018427387904   4611686018427387904",
+        )
+        .unwrap();
+        assert!(matches!(
+            solve_part_two(&left, &right),
+            Err(Error::Overflow)
+        ));
+        assert!(matches!(
+            Day1::part_two(&(left, right)),
+            Err(Error::Overflow)
+        ));
+
+        // Each distance fits but the total does not
+        let (left, right) = parse_input(
+            "0   9223372036854775807
+0   1",
+        )
+        .unwrap();
+        assert!(matches!(
+            solve_part_one(&left, &right),
+            Err(Error::Overflow)
+        ));
+    }
+
+    #[test]
+    fn rejects_truncated_input() {
+        let error = parse_input("3   4\n4   3\n2").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 2));
+    }
+
+    #[test]
+    fn rejects_corrupted_input() {
+        let error = parse_input("3   4\n4   x\n2   5\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(error.kind, ErrorKind::Digit);
+
+        let error = parse_input("3   4\n4   3   9\n2   5\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 9));
+    }
+}
@@ -0,0 +1,215 @@
+use nom::{character::complete::space0, sequence::preceded};
+use parsing::{integer, parse_all, ParseError};
+use std::fmt;
+
+use crate::{parse_two_numbers, Error};
+
+/// Which of the two lists a location ID belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    Left,
+    Right,
+}
+
+impl fmt::Display for Column {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Column::Left => write!(f, "left"),
+            Column::Right => write!(f, "right"),
+        }
+    }
+}
+
+/// Why a line was not accepted as a pair of location IDs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reason {
+    /// Only one ID is on the line. It still goes into its own list, so the
+    /// lists end up with different lengths.
+    Missing(Column),
+    /// The line could not be read at all and was skipped.
+    Malformed(ParseError),
+}
+
+/// A line that was not accepted as it stood.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// 1-based line number.
+    pub line: usize,
+    /// The line as it appears in the input, without its line ending.
+    pub content: String,
+    pub reason: Reason,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.reason {
+            Reason::Missing(column) => write!(
+                f,
+                "line {}: no {} location ID: `{}`",
+                self.line, column, self.content
+            ),
+            Reason::Malformed(e) => write!(
+                f,
+                "line {}, column {}: {}: `{}`",
+                self.line,
+                e.column,
+                e.reason(),
+                self.content
+            ),
+        }
+    }
+}
+
+/// The location lists as far as they could be read, along with everything
+/// that was wrong with the input.
+#[derive(Debug, Default)]
+pub struct Loaded {
+    pub left: Vec<i64>,
+    pub right: Vec<i64>,
+    /// How many lines held a full pair of IDs.
+    pub accepted: usize,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Loaded {
+    /// Both lists, sorted and ready to solve.
+    ///
+    /// Fails if they ended up with different lengths, unless `allow_mismatch`
+    /// is set; part one then ignores the unpaired IDs at the end of the longer
+    /// list.
+    pub fn into_sorted(mut self, allow_mismatch: bool) -> Result<(Vec<i64>, Vec<i64>), Error> {
+        if self.left.len() != self.right.len() && !allow_mismatch {
+            return Err(Error::MismatchedLengths {
+                left: self.left.len(),
+                right: self.right.len(),
+            });
+        }
+
+        self.left.sort();
+        self.right.sort();
+        Ok((self.left, self.right))
+    }
+}
+
+/// Reads the location lists line by line, keeping every ID it can and noting
+/// every line it could not fully accept rather than stopping at the first.
+///
+/// Blank lines are ignored. A line with a single ID is taken to be missing its
+/// right ID, unless it is indented, in which case it is missing its left one.
+pub fn load(input: &str) -> Loaded {
+    let mut loaded = Loaded::default();
+    let mut offset = 0;
+
+    for (index, raw) in input.split('\n').enumerate() {
+        let line = raw.strip_suffix('\r').unwrap_or(raw);
+        let line_offset = offset;
+        offset += raw.len() + 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let diagnostic = |reason| Diagnostic {
+            line: index + 1,
+            content: line.to_string(),
+            reason,
+        };
+
+        match parse_all(preceded(space0, parse_two_numbers), line) {
+            Ok((left, right)) => {
+                loaded.left.push(left);
+                loaded.right.push(right);
+                loaded.accepted += 1;
+            }
+            Err(e) => match parse_all(preceded(space0, integer::<i64>), line) {
+                Ok(id) if line.starts_with([' ', '\t']) => {
+                    loaded.right.push(id);
+                    let diagnostic = diagnostic(Reason::Missing(Column::Left));
+                    loaded.diagnostics.push(diagnostic);
+                }
+                Ok(id) => {
+                    loaded.left.push(id);
+                    let diagnostic = diagnostic(Reason::Missing(Column::Right));
+                    loaded.diagnostics.push(diagnostic);
+                }
+                Err(_) => {
+                    let e = e.on_line(index + 1, line_offset);
+                    loaded.diagnostics.push(diagnostic(Reason::Malformed(e)));
+                }
+            },
+        }
+    }
+
+    loaded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::error::ErrorKind;
+
+    #[test]
+    fn clean_input() {
+        let loaded = load(crate::EXAMPLE);
+        assert_eq!(loaded.accepted, 6);
+        assert!(loaded.diagnostics.is_empty());
+        let (left, right) = loaded.into_sorted(false).unwrap();
+        assert_eq!(left, vec![1, 2, 3, 3, 3, 4]);
+        assert_eq!(right, vec![3, 3, 3, 4, 5, 9]);
+    }
+
+    #[test]
+    fn reports_every_bad_line() {
+        let loaded = load("3   4\r\n4   x\n\n2   5\n1 3 7\n3   9\n");
+        assert_eq!(loaded.accepted, 3);
+        assert_eq!(loaded.left, vec![3, 2, 3]);
+        assert_eq!(loaded.diagnostics.len(), 2);
+
+        let first = &loaded.diagnostics[0];
+        assert_eq!((first.line, first.content.as_str()), (2, "4   x"));
+        let Reason::Malformed(e) = &first.reason else {
+            panic!("expected a malformed line, got {:?}", first.reason);
+        };
+        assert_eq!((e.line, e.column, e.offset), (2, 5, 11));
+        assert_eq!(e.kind, ErrorKind::Digit);
+        assert_eq!(
+            first.to_string(),
+            "line 2, column 5: expected a number: `4   x`"
+        );
+
+        assert_eq!(loaded.diagnostics[1].line, 5);
+    }
+
+    #[test]
+    fn lone_ids_go_into_their_column() {
+        let loaded = load("3   4\n7\n   8\n   9\n");
+        assert_eq!(loaded.accepted, 1);
+        assert_eq!(loaded.left, vec![3, 7]);
+        assert_eq!(loaded.right, vec![4, 8, 9]);
+        let reasons: Vec<&Reason> = loaded.diagnostics.iter().map(|d| &d.reason).collect();
+        assert_eq!(
+            reasons,
+            vec![
+                &Reason::Missing(Column::Right),
+                &Reason::Missing(Column::Left),
+                &Reason::Missing(Column::Left)
+            ]
+        );
+        assert_eq!(
+            loaded.diagnostics[0].to_string(),
+            "line 2: no right location ID: `7`"
+        );
+    }
+
+    #[test]
+    fn mismatched_lengths_need_permission() {
+        let error = load("3   4\n4   3\n   9\n").into_sorted(false).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::MismatchedLengths { left: 2, right: 3 }
+        ));
+
+        let (left, right) = load("3   4\n4   1\n   9\n").into_sorted(true).unwrap();
+        assert_eq!(crate::solve_part_one(&left, &right).unwrap(), 2);
+        assert_eq!(crate::solve_part_two(&left, &right).unwrap(), 4);
+    }
+}
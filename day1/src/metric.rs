@@ -0,0 +1,71 @@
+/// How far apart two sorted columns are, built up one pair of IDs at a time.
+pub trait Metric {
+    /// How far apart a single pair of IDs is.
+    fn distance(&self, a: i64, b: i64) -> u128;
+
+    /// Folds the distance of the next pair into the total so far, or `None` if
+    /// the total no longer fits. Totals add up unless a metric says otherwise.
+    fn combine(&self, total: u128, distance: u128) -> Option<u128> {
+        total.checked_add(distance)
+    }
+}
+
+/// The sum of absolute differences, as part one uses.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct L1;
+
+impl Metric for L1 {
+    fn distance(&self, a: i64, b: i64) -> u128 {
+        a.abs_diff(b) as u128
+    }
+}
+
+/// The sum of squared differences, which punishes a few large gaps more than
+/// many small ones.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Squared;
+
+impl Metric for Squared {
+    fn distance(&self, a: i64, b: i64) -> u128 {
+        let difference = a.abs_diff(b) as u128;
+        difference * difference
+    }
+}
+
+/// The single largest difference.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Max;
+
+impl Metric for Max {
+    fn distance(&self, a: i64, b: i64) -> u128 {
+        a.abs_diff(b) as u128
+    }
+
+    fn combine(&self, total: u128, distance: u128) -> Option<u128> {
+        Some(total.max(distance))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_pairs() {
+        assert_eq!(L1.distance(3, -4), 7);
+        assert_eq!(Squared.distance(3, -4), 49);
+        assert_eq!(Max.distance(-4, 3), 7);
+        assert_eq!(
+            Squared.distance(i64::MIN, i64::MAX),
+            u64::MAX as u128 * u64::MAX as u128
+        );
+    }
+
+    #[test]
+    fn combining() {
+        assert_eq!(L1.combine(2, 3), Some(5));
+        assert_eq!(Squared.combine(u128::MAX, 1), None);
+        assert_eq!(Max.combine(2, 3), Some(3));
+        assert_eq!(Max.combine(u128::MAX, 1), Some(u128::MAX));
+    }
+}
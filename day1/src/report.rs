@@ -0,0 +1,115 @@
+use std::{
+    cmp::Reverse,
+    io::{self, Write},
+};
+
+/// One step of the matching behind part one: the `rank`-th smallest ID of each
+/// list, side by side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pairing {
+    /// 1-based position in the sorted lists.
+    pub rank: usize,
+    pub left: i64,
+    pub right: i64,
+    pub distance: u64,
+    /// The total distance of this pair and every pair before it, which as a
+    /// `u128` cannot overflow.
+    pub running_total: u128,
+}
+
+/// Every pair that part one adds up, in order. `left` and `right` must already
+/// be sorted, as they are coming out of [`crate::parse_input`].
+pub fn pairings<'a>(left: &'a [i64], right: &'a [i64]) -> impl Iterator<Item = Pairing> + 'a {
+    left.iter()
+        .zip(right)
+        .enumerate()
+        .scan(0, |total, (index, (&left, &right))| {
+            let distance = left.abs_diff(right);
+            *total += distance as u128;
+            Some(Pairing {
+                rank: index + 1,
+                left,
+                right,
+                distance,
+                running_total: *total,
+            })
+        })
+}
+
+/// The `n` pairs furthest apart, largest first. Equal gaps keep their order.
+pub fn largest_gaps(left: &[i64], right: &[i64], n: usize) -> Vec<Pairing> {
+    let mut gaps: Vec<Pairing> = pairings(left, right).collect();
+    gaps.sort_by_key(|pairing| Reverse(pairing.distance));
+    gaps.truncate(n);
+    gaps
+}
+
+/// Writes `pairings` out as CSV, with a header row.
+pub fn write_csv(
+    pairings: impl IntoIterator<Item = Pairing>,
+    mut writer: impl Write,
+) -> io::Result<()> {
+    writeln!(writer, "rank,left,right,distance,running_total")?;
+    for pairing in pairings {
+        writeln!(
+            writer,
+            "{},{},{},{},{}",
+            pairing.rank, pairing.left, pairing.right, pairing.distance, pairing.running_total
+        )?;
+    }
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn running_total_ends_at_the_answer() {
+        let (left, right) = crate::parse_input(crate::EXAMPLE).unwrap();
+        let pairs: Vec<Pairing> = pairings(&left, &right).collect();
+        assert_eq!(pairs.len(), 6);
+        assert_eq!(
+            pairs[0],
+            Pairing {
+                rank: 1,
+                left: 1,
+                right: 3,
+                distance: 2,
+                running_total: 2
+            }
+        );
+        assert_eq!(
+            pairs.last().unwrap().running_total,
+            crate::solve_part_one(&left, &right).unwrap() as u128
+        );
+    }
+
+    #[test]
+    fn largest_gaps_first() {
+        let (left, right) = crate::parse_input(crate::EXAMPLE).unwrap();
+        let gaps: Vec<(usize, u64)> = largest_gaps(&left, &right, 3)
+            .iter()
+            .map(|pairing| (pairing.rank, pairing.distance))
+            .collect();
+        assert_eq!(gaps, vec![(6, 5), (1, 2), (5, 2)]);
+        assert_eq!(largest_gaps(&left, &right, 100).len(), 6);
+    }
+
+    #[test]
+    fn extreme_ids() {
+        let pairs: Vec<Pairing> = pairings(&[i64::MIN, i64::MIN], &[i64::MAX, i64::MAX]).collect();
+        assert_eq!(pairs[0].distance, u64::MAX);
+        assert_eq!(pairs[1].running_total, 2 * u64::MAX as u128);
+    }
+
+    #[test]
+    fn csv() {
+        let mut output = Vec::new();
+        write_csv(pairings(&[1, 3], &[2, 3]), &mut output).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "rank,left,right,distance,running_total\n1,1,2,1,1\n2,3,3,0,1\n"
+        );
+    }
+}
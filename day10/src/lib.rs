@@ -0,0 +1,143 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::Solution;
+use grid::{Grid, Point};
+use nom::{character::complete::satisfy, combinator::map, IResult};
+use parsing::{grid, parse_all, ParseError};
+
+fn parse_height(input: &str) -> IResult<&str, u8> {
+    map(satisfy(|c| c.is_ascii_digit()), |c| c as u8 - b'0')(input)
+}
+
+pub fn parse_input(input: &str) -> Result<Grid<u8>, ParseError> {
+    parse_all(grid(parse_height), input)
+}
+
+/// The worked example from the puzzle description.
+pub const EXAMPLE: &str =
+    "89010123\n78121874\n87430965\n96549874\n45678903\n32019012\n01329801\n10456732";
+
+const PEAK: u8 = 9;
+
+/// Where a hiking trail starting from some position can end up.
+#[derive(Debug, Clone, Default)]
+struct Reachable {
+    /// Every distinct 9 that some trail reaches.
+    peaks: HashSet<Point>,
+    /// The number of distinct trails, counting each route to a 9 separately.
+    trails: usize,
+}
+
+/// Everything reachable from `position` by climbing exactly one step of height
+/// at a time. Results are memoised in `results`, since trails from different
+/// trailheads soon run into each other.
+fn reachable(
+    map: &Grid<u8>,
+    position: Point,
+    results: &mut HashMap<Point, Reachable>,
+) -> Reachable {
+    if let Some(found) = results.get(&position) {
+        return found.clone();
+    }
+
+    let height = map[position];
+    let found = if height == PEAK {
+        Reachable {
+            peaks: HashSet::from([position]),
+            trails: 1,
+        }
+    } else {
+        let uphill: Vec<Point> = map
+            .neighbours4(position)
+            .filter(|(_, &next)| next == height + 1)
+            .map(|(next, _)| next)
+            .collect();
+
+        uphill
+            .into_iter()
+            .fold(Reachable::default(), |mut acc, next| {
+                let found = reachable(map, next, results);
+                acc.peaks.extend(found.peaks);
+                acc.trails += found.trails;
+                acc
+            })
+    };
+
+    results.insert(position, found.clone());
+    found
+}
+
+fn trailheads(map: &Grid<u8>) -> Vec<Reachable> {
+    let mut results = HashMap::new();
+    map.positions(|&height| height == 0)
+        .map(|trailhead| reachable(map, trailhead, &mut results))
+        .collect()
+}
+
+/// The sum of every trailhead's score: how many 9s it can reach.
+pub fn solve_part_one(map: &Grid<u8>) -> usize {
+    trailheads(map).iter().map(|found| found.peaks.len()).sum()
+}
+
+/// The sum of every trailhead's rating: how many distinct trails start there.
+pub fn solve_part_two(map: &Grid<u8>) -> usize {
+    trailheads(map).iter().map(|found| found.trails).sum()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<u8>;
+    type Answer = usize;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(solve_part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(solve_part_two(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_full_input() {
+        let result = parse_input(EXAMPLE).expect("failed to parse");
+        assert_eq!(result.width(), 8);
+        assert_eq!(result.height(), 8);
+        assert_eq!(result.row(0).unwrap(), [8, 9, 0, 1, 0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn single_trailhead() {
+        let parsed = parse_input("0123\n1234\n8765\n9876").expect("failed to parse");
+        assert_eq!(solve_part_one(&parsed), 1);
+        assert_eq!(solve_part_two(&parsed), 16);
+    }
+
+    #[test]
+    fn test_part_one() {
+        let parsed = parse_input(EXAMPLE).expect("failed to parse");
+        assert_eq!(solve_part_one(&parsed), 36);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let parsed = parse_input(EXAMPLE).expect("failed to parse");
+        assert_eq!(solve_part_two(&parsed), 81);
+    }
+
+    #[test]
+    fn rejects_corrupted_input() {
+        let error = parse_input("0123\n12.4\n8765").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+}
@@ -0,0 +1,191 @@
+use std::collections::{hash_map::Entry, HashMap};
+
+use aoc_core::Solution;
+use nom::{character::complete::space1, multi::separated_list1};
+use num_bigint::BigUint;
+use num_traits::CheckedAdd;
+use parsing::{parse_all, unsigned, ParseError};
+
+pub fn parse_input(input: &str) -> Result<Vec<u64>, ParseError> {
+    parse_all(separated_list1(space1, unsigned), input)
+}
+
+/// The worked example from the puzzle description.
+pub const EXAMPLE: &str = "125 17";
+
+/// What a single stone turns into when you blink.
+///
+/// Stones that start out as a `u64` never outgrow a `u128`: only a number
+/// with an odd count of digits grows, and multiplying it by 2024 either gives
+/// an even count, which splits next time, or an odd one starting with a 1 or
+/// a 2, which gets an even count from the next multiplication. So a stone
+/// never gets past 26 digits, where `u128` holds 38.
+pub fn blink(stone: u128) -> Vec<u128> {
+    if stone == 0 {
+        return vec![1];
+    }
+
+    let digits = stone.ilog10() + 1;
+    if digits.is_multiple_of(2) {
+        let half = 10u128.pow(digits / 2);
+        return vec![stone / half, stone % half];
+    }
+
+    vec![stone * 2024]
+}
+
+/// Blinks once across a whole row of stones, tracked as how many stones carry
+/// each number since their order never matters.
+///
+/// Returns `None` if a count overflows `C`.
+fn blink_all<C: CheckedAdd + Clone>(stones: &HashMap<u128, C>) -> Option<HashMap<u128, C>> {
+    let mut next: HashMap<u128, C> = HashMap::with_capacity(stones.len());
+    for (&stone, count) in stones {
+        for new_stone in blink(stone) {
+            match next.entry(new_stone) {
+                Entry::Occupied(mut entry) => {
+                    let total = entry.get().checked_add(count)?;
+                    entry.insert(total);
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(count.clone());
+                }
+            }
+        }
+    }
+    Some(next)
+}
+
+/// How many stones there are after blinking `blinks` times.
+///
+/// Counts are kept in a `u128` for as long as they fit, and moved over to big
+/// integers once they no longer do.
+pub fn count_stones(stones: &[u64], blinks: usize) -> BigUint {
+    let mut counts: HashMap<u128, u128> = HashMap::new();
+    for &stone in stones {
+        *counts.entry(u128::from(stone)).or_default() += 1;
+    }
+
+    for done in 0..blinks {
+        match blink_all(&counts) {
+            Some(next) => counts = next,
+            None => {
+                let mut counts: HashMap<u128, BigUint> = counts
+                    .into_iter()
+                    .map(|(stone, count)| (stone, BigUint::from(count)))
+                    .collect();
+                for _ in done..blinks {
+                    counts = blink_all(&counts).expect("big integers do not overflow");
+                }
+                return counts.into_values().sum();
+            }
+        }
+    }
+
+    // Every count fits in a u128 but their total might not
+    counts.into_values().map(BigUint::from).sum()
+}
+
+pub fn solve_part_one(stones: &[u64]) -> BigUint {
+    count_stones(stones, 25)
+}
+
+pub fn solve_part_two(stones: &[u64]) -> BigUint {
+    count_stones(stones, 75)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<u64>;
+    type Answer = BigUint;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(solve_part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(solve_part_two(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blink_zero() {
+        assert_eq!(blink(0), vec![1]);
+    }
+
+    #[test]
+    fn blink_even_digits() {
+        assert_eq!(blink(17), vec![1, 7]);
+        assert_eq!(blink(1000), vec![10, 0]);
+        assert_eq!(blink(253000), vec![253, 0]);
+    }
+
+    #[test]
+    fn blink_odd_digits() {
+        assert_eq!(blink(1), vec![2024]);
+        assert_eq!(blink(125), vec![253000]);
+    }
+
+    #[test]
+    fn stones_past_u64() {
+        assert_eq!(
+            blink(1_000_000_000_000_000_001),
+            vec![2_024_000_000_000_000_002_024]
+        );
+
+        // Every digit count a u64 can start with, blinked until the numbers
+        // have long since settled down
+        for digits in 1..=20 {
+            let stone = u64::MAX / 10u64.pow(20 - digits);
+            let mut stones = HashMap::from([(u128::from(stone), 1u128)]);
+            for _ in 0..40 {
+                stones = blink_all(&stones).unwrap();
+                assert!(stones.keys().all(|&stone| stone < 10u128.pow(26)));
+            }
+        }
+        assert_eq!(count_stones(&[999_999_999_999_999], 5), BigUint::from(3u32));
+    }
+
+    #[test]
+    fn parse_full_input() {
+        assert_eq!(parse_input(EXAMPLE).unwrap(), vec![125, 17]);
+        assert_eq!(
+            parse_input("0 1 10 99 999\n").unwrap(),
+            vec![0, 1, 10, 99, 999]
+        );
+        assert!(parse_input("125 -17").is_err());
+    }
+
+    #[test]
+    fn test_part_one() {
+        let stones = parse_input(EXAMPLE).expect("failed to parse");
+        assert_eq!(count_stones(&stones, 6), BigUint::from(22u32));
+        assert_eq!(solve_part_one(&stones), BigUint::from(55312u32));
+    }
+
+    #[test]
+    fn counts_beyond_u128() {
+        let stones = parse_input(EXAMPLE).expect("failed to parse");
+        let blinks = 300;
+
+        let mut counts: HashMap<u128, BigUint> =
+            HashMap::from([(125, BigUint::from(1u32)), (17, BigUint::from(1u32))]);
+        for _ in 0..blinks {
+            counts = blink_all(&counts).unwrap();
+        }
+        let expected: BigUint = counts.into_values().sum();
+
+        assert!(expected > BigUint::from(u128::MAX));
+        assert_eq!(count_stones(&stones, blinks), expected);
+    }
+}
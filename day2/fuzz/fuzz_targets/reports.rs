@@ -0,0 +1,29 @@
+#![no_main]
+
+use day2::{Direction, SafetyPolicy};
+use libfuzzer_sys::fuzz_target;
+
+// Any input that parses, judged under any policy, must come back with a
+// verdict rather than a panic.
+fuzz_target!(|data: (u32, u32, u8, u8, &str)| {
+    let (min_step, max_step, direction, max_removals, input) = data;
+    let policy = SafetyPolicy {
+        min_step,
+        max_step,
+        direction: match direction % 3 {
+            0 => Direction::Increasing,
+            1 => Direction::Decreasing,
+            _ => Direction::Either,
+        },
+        max_removals: max_removals as usize,
+    };
+
+    if let Ok(reports) = day2::parse_lines(input) {
+        day2::count_safe(&reports, &policy);
+        day2::count_safe(&reports, &SafetyPolicy::PART_TWO);
+        for levels in &reports {
+            let verdict = day2::judge(levels, &policy);
+            assert_eq!(verdict.is_safe(), day2::dampen(levels, &policy).is_some());
+        }
+    }
+});
@@ -0,0 +1,157 @@
+use crate::{
+    verdict::{trend, Trend},
+    SafetyPolicy,
+};
+
+/// Whether `from` can be followed by `to` in a report heading `heading`.
+fn allows(policy: &SafetyPolicy, heading: Trend, from: i32, to: i32) -> bool {
+    policy.allows_step(from.abs_diff(to)) && trend(from, to).is_none_or(|actual| actual == heading)
+}
+
+/// The fewest removals that leave `levels` heading `heading` throughout, or
+/// `None` if it takes more than the policy allows.
+///
+/// With at most `k` removals, the next level kept after any kept level is at
+/// most `k + 1` further on, so `after[j]`, the fewest removals after `j` given
+/// that `j` is kept, only has that many choices to look at.
+fn fewest_removals(levels: &[i32], policy: &SafetyPolicy, heading: Trend) -> Option<Vec<usize>> {
+    let n = levels.len();
+    if n == 0 {
+        return Some(Vec::new());
+    }
+    let k = policy.max_removals;
+    let reach = |from: usize| from + 1..=from.saturating_add(k.saturating_add(1)).min(n - 1);
+
+    let mut after = vec![0; n];
+    for j in (0..n).rev() {
+        after[j] = reach(j)
+            .filter(|&i| allows(policy, heading, levels[j], levels[i]))
+            .map(|i| i - j - 1 + after[i])
+            .fold(n - 1 - j, usize::min);
+    }
+    let total = (0..=k.min(n - 1)).map(|i| i + after[i]).fold(n, usize::min);
+    if total > k {
+        return None;
+    }
+
+    // Walk forwards through one of the best choices. Keeping the next level as
+    // late as possible removes the earliest levels, which is the order the
+    // removals were tried in when this was a brute-force search.
+    let mut removed = Vec::with_capacity(total);
+    let mut next = if total == n {
+        None
+    } else {
+        (0..=k.min(n - 1)).rev().find(|&i| i + after[i] == total)
+    };
+    let mut start = 0;
+    while let Some(kept) = next {
+        removed.extend(start..kept);
+        start = kept + 1;
+        let remaining = after[kept];
+        next = if remaining == n - 1 - kept {
+            None
+        } else {
+            reach(kept).rev().find(|&i| {
+                allows(policy, heading, levels[kept], levels[i])
+                    && i - kept - 1 + after[i] == remaining
+            })
+        };
+    }
+    removed.extend(start..n);
+    Some(removed)
+}
+
+/// The fewest levels to remove to make `levels` safe under `policy`, or `None`
+/// if that takes more than `policy.max_removals`. A report that is already safe
+/// needs no removals. When there is a choice, the earliest levels are removed.
+///
+/// This takes O(n·k) time for `n` levels and at most `k` removals.
+pub fn dampen(levels: &[i32], policy: &SafetyPolicy) -> Option<Vec<usize>> {
+    policy
+        .headings()
+        .iter()
+        .filter_map(|&heading| fewest_removals(levels, policy, heading))
+        .min_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{first_violation, policy::tests::policies};
+    use proptest::prelude::*;
+
+    /// Every set of removals in order of size and then lexicographically, until
+    /// one leaves a safe report.
+    fn brute_force(levels: &[i32], policy: &SafetyPolicy) -> Option<Vec<usize>> {
+        fn search(
+            levels: &[i32],
+            policy: &SafetyPolicy,
+            count: usize,
+            start: usize,
+            chosen: &mut Vec<usize>,
+        ) -> bool {
+            if chosen.len() == count {
+                let kept: Vec<i32> = (0..levels.len())
+                    .filter(|index| !chosen.contains(index))
+                    .map(|index| levels[index])
+                    .collect();
+                return first_violation(&kept, policy).is_none();
+            }
+            for index in start..levels.len() {
+                chosen.push(index);
+                if search(levels, policy, count, index + 1, chosen) {
+                    return true;
+                }
+                chosen.pop();
+            }
+            false
+        }
+
+        (0..=policy.max_removals.min(levels.len())).find_map(|count| {
+            let mut chosen = Vec::new();
+            search(levels, policy, count, 0, &mut chosen).then_some(chosen)
+        })
+    }
+
+    #[test]
+    fn example() {
+        let policy = SafetyPolicy::PART_TWO;
+        assert_eq!(dampen(&[7, 6, 4, 2, 1], &policy), Some(vec![]));
+        assert_eq!(dampen(&[1, 2, 7, 8, 9], &policy), None);
+        assert_eq!(dampen(&[1, 3, 2, 4, 5], &policy), Some(vec![1]));
+        assert_eq!(dampen(&[8, 6, 4, 4, 1], &policy), Some(vec![2]));
+    }
+
+    #[test]
+    fn many_removals() {
+        let policy = SafetyPolicy {
+            max_removals: 3,
+            ..SafetyPolicy::PART_ONE
+        };
+        assert_eq!(dampen(&[1, 9, 2, 9, 3, 9], &policy), Some(vec![1, 3, 5]));
+        assert_eq!(dampen(&[5, 1, 9, 9, 6, 7], &policy), Some(vec![1, 2, 3]));
+        assert_eq!(dampen(&[5, 9, 9, 9, 9, 6, 7], &policy), None);
+    }
+
+    #[test]
+    fn long_reports() {
+        let mut levels: Vec<i32> = (0..100_000).collect();
+        levels[500] = -1;
+        levels[70_000] = 0;
+        let policy = SafetyPolicy {
+            max_removals: 2,
+            ..SafetyPolicy::PART_ONE
+        };
+        assert_eq!(dampen(&levels, &policy), Some(vec![500, 70_000]));
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force(
+            levels in prop::collection::vec(0i32..8, 0..10),
+            policy in policies(0usize..5),
+        ) {
+            prop_assert_eq!(dampen(&levels, &policy), brute_force(&levels, &policy));
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use aoc_core::Solution;
+use nom::{character::complete::space1, multi::separated_list1, IResult};
+use parsing::{integer, lines, parse_all, ParseError};
+use std::fmt;
+
+mod dampener;
+mod longest;
+mod policy;
+mod repair;
+mod verdict;
+
+pub use dampener::dampen;
+pub use longest::{longest_safe, removal_histogram};
+pub use policy::{Direction, SafetyPolicy};
+pub use repair::{repair, Costs, Repair};
+pub use verdict::{first_violation, judge, Trend, Verdict, Violation};
+
+fn parse_numbers(input: &str) -> IResult<&str, Vec<i32>> {
+    separated_list1(space1, integer)(input)
+}
+pub fn parse_lines(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    parse_all(lines(parse_numbers), input)
+}
+
+/// The worked example from the puzzle description.
+pub const EXAMPLE: &str = "7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n1 3 2 4 5\n8 6 4 4 1\n1 3 6 7 9";
+
+/// How many reports are safe under `policy`.
+pub fn count_safe(input: &[Vec<i32>], policy: &SafetyPolicy) -> i32 {
+    input.iter().fold(0, |acc, line| {
+        if judge(line, policy).is_safe() {
+            acc + 1
+        } else {
+            acc
+        }
+    })
+}
+
+pub fn solve_part_one(input: &[Vec<i32>]) -> i32 {
+    count_safe(input, &SafetyPolicy::PART_ONE)
+}
+
+pub fn solve_part_two(input: &[Vec<i32>]) -> i32 {
+    count_safe(input, &SafetyPolicy::PART_TWO)
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Parse(ParseError),
+    /// A safety policy that is not valid TOML or has the wrong fields.
+    Policy(toml::de::Error),
+    /// A safety policy whose smallest step is larger than its largest.
+    StepRange {
+        min_step: u32,
+        max_step: u32,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(e) => write!(f, "{}", e),
+            Error::Policy(e) => write!(f, "invalid safety policy: {}", e),
+            Error::StepRange { min_step, max_step } => write!(
+                f,
+                "the smallest step ({}) is larger than the largest ({})",
+                min_step, max_step
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+impl From<toml::de::Error> for Error {
+    fn from(e: toml::de::Error) -> Self {
+        Error::Policy(e)
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<i32>>;
+    type Answer = i32;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(parse_lines(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(solve_part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(solve_part_two(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::error::ErrorKind;
+    use proptest::prelude::*;
+
+    #[test]
+    fn is_safe() {
+        let input: Vec<i32> = vec![7, 6, 4, 2, 1];
+        let result = judge(&input, &SafetyPolicy::PART_TWO);
+        assert_eq!(result, Verdict::Safe);
+    }
+
+    #[test]
+    fn always_unsafe() {
+        let input = vec![1, 2, 7, 8, 9];
+        let result = judge(&input, &SafetyPolicy::PART_TWO);
+        assert!(matches!(
+            result,
+            Verdict::Unsafe(Violation::Step { index: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn safe_with_removals() {
+        let input = vec![1, 3, 2, 4, 5];
+        let result = judge(&input, &SafetyPolicy::PART_TWO);
+        assert!(result.is_safe());
+        assert!(matches!(result, Verdict::Dampened { removed, .. } if removed == [(1, 3)]));
+    }
+
+    #[test]
+    fn test_solutions() {
+        let parsed = parse_lines(EXAMPLE).expect("failed to parse");
+        assert_eq!(solve_part_one(&parsed), 2);
+        assert_eq!(solve_part_two(&parsed), 4);
+    }
+
+    #[test]
+    fn rejects_corrupted_input() {
+        let error = parse_lines("7 6 4 2 1\n1 2 7,8 9\n9 7 6 2 1\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 6));
+
+        let error = parse_lines("7 6 4 2 1\n1 2 7 8 x\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 9));
+        assert_eq!(error.kind, ErrorKind::Eof);
+    }
+
+    proptest! {
+        // The fuzz target in `fuzz/` goes further, but this keeps the
+        // guarantee checked on every test run: whatever parses gets a verdict
+        #[test]
+        fn every_parsed_report_gets_a_verdict(
+            input in "(-?[0-9]{1,10}[ \n]{1,2}){0,12}",
+            max_removals in 0usize..4,
+        ) {
+            if let Ok(reports) = parse_lines(&input) {
+                let policy = SafetyPolicy { max_removals, ..SafetyPolicy::PART_ONE };
+                for levels in &reports {
+                    let verdict = judge(levels, &policy);
+                    prop_assert_eq!(verdict.is_safe(), dampen(levels, &policy).is_some());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{verdict::Trend, SafetyPolicy};
+
+/// The largest `(length, index)` over ranges of level values, for the chains
+/// found so far.
+struct Chains {
+    tree: Vec<(usize, usize)>,
+    size: usize,
+}
+
+impl Chains {
+    fn new(size: usize) -> Self {
+        Chains {
+            tree: vec![(0, 0); 2 * size],
+            size,
+        }
+    }
+
+    fn insert(&mut self, value: usize, chain: (usize, usize)) {
+        let mut node = value + self.size;
+        while node > 0 {
+            self.tree[node] = self.tree[node].max(chain);
+            node /= 2;
+        }
+    }
+
+    /// The best chain at a value in `from..to`, or `(0, 0)` if there is none.
+    fn best(&self, from: usize, to: usize) -> (usize, usize) {
+        let (mut from, mut to) = (from + self.size, to + self.size);
+        let mut best = (0, 0);
+        while from < to {
+            if from % 2 == 1 {
+                best = best.max(self.tree[from]);
+                from += 1;
+            }
+            if to % 2 == 1 {
+                to -= 1;
+                best = best.max(self.tree[to]);
+            }
+            from /= 2;
+            to /= 2;
+        }
+        best
+    }
+}
+
+/// The longest chain of `levels` heading `heading`, keeping the latest levels
+/// when there is a choice.
+///
+/// Working backwards, the longest chain from each level continues with the
+/// best chain from a later level whose value is a step away, which a segment
+/// tree over the values finds in O(log n).
+fn longest_heading(levels: &[i32], policy: &SafetyPolicy, heading: Trend) -> Vec<usize> {
+    let mut values: Vec<i64> = levels.iter().map(|&level| i64::from(level)).collect();
+    values.sort_unstable();
+    values.dedup();
+    let rank = |value: i64| values.partition_point(|&other| other < value);
+
+    let (min_step, max_step) = (i64::from(policy.min_step), i64::from(policy.max_step));
+    let mut chains = Chains::new(values.len());
+    let mut next = vec![None; levels.len()];
+    for (index, &level) in levels.iter().enumerate().rev() {
+        let level = i64::from(level);
+        let (low, high) = match heading {
+            Trend::Increasing => (level + min_step, level + max_step),
+            Trend::Decreasing => (level - max_step, level - min_step),
+        };
+        let (length, after) = chains.best(rank(low), rank(high + 1));
+        next[index] = (length > 0).then_some(after);
+        chains.insert(rank(level), (length + 1, index));
+    }
+
+    let (_, first) = chains.best(0, values.len());
+    let mut kept = Vec::new();
+    let mut current = (!levels.is_empty()).then_some(first);
+    while let Some(index) = current {
+        kept.push(index);
+        current = next[index];
+    }
+    kept
+}
+
+/// The indices of the longest subsequence of `levels` that is safe under
+/// `policy` with nothing removed, in order. When there is a choice, the latest
+/// levels are kept. `policy.max_removals` is not used.
+///
+/// This takes O(n log n) time for `n` levels.
+pub fn longest_safe(levels: &[i32], policy: &SafetyPolicy) -> Vec<usize> {
+    policy
+        .headings()
+        .iter()
+        .map(|&heading| longest_heading(levels, policy, heading))
+        .max_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)))
+        .unwrap_or_default()
+}
+
+/// How many reports need each number of removals to be safe under `policy`:
+/// `histogram[k]` counts the reports that need exactly `k`. It is only as long
+/// as the largest number needed.
+pub fn removal_histogram(reports: &[Vec<i32>], policy: &SafetyPolicy) -> Vec<usize> {
+    let mut histogram = Vec::new();
+    for levels in reports {
+        let removals = levels.len() - longest_safe(levels, policy).len();
+        if histogram.len() <= removals {
+            histogram.resize(removals + 1, 0);
+        }
+        histogram[removals] += 1;
+    }
+    histogram
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dampen, first_violation, parse_lines, policy::tests::policies, EXAMPLE};
+    use proptest::prelude::*;
+
+    #[test]
+    fn example() {
+        let policy = SafetyPolicy::PART_ONE;
+        assert_eq!(longest_safe(&[7, 6, 4, 2, 1], &policy), vec![0, 1, 2, 3, 4]);
+        assert_eq!(longest_safe(&[1, 2, 7, 8, 9], &policy), vec![2, 3, 4]);
+        assert_eq!(longest_safe(&[9, 7, 6, 2, 1], &policy), vec![0, 1, 2]);
+        assert_eq!(longest_safe(&[1, 3, 2, 4, 5], &policy), vec![0, 2, 3, 4]);
+
+        let reports = parse_lines(EXAMPLE).unwrap();
+        assert_eq!(removal_histogram(&reports, &policy), vec![2, 2, 2]);
+    }
+
+    #[test]
+    fn short_reports() {
+        let policy = SafetyPolicy::PART_ONE;
+        assert_eq!(longest_safe(&[], &policy), Vec::<usize>::new());
+        assert_eq!(longest_safe(&[4], &policy), vec![0]);
+        assert_eq!(longest_safe(&[4, 4], &policy), vec![1]);
+        assert_eq!(removal_histogram(&[], &policy), Vec::<usize>::new());
+    }
+
+    proptest! {
+        #[test]
+        fn nothing_longer_is_safe(levels in prop::collection::vec(0i32..8, 0..10)) {
+            let policy = SafetyPolicy::PART_ONE;
+            let kept = longest_safe(&levels, &policy);
+            let kept_levels: Vec<i32> = kept.iter().map(|&index| levels[index]).collect();
+            prop_assert!(first_violation(&kept_levels, &policy).is_none());
+
+            let longest = (0u32..1 << levels.len())
+                .filter(|mask| {
+                    let subsequence: Vec<i32> = (0..levels.len())
+                        .filter(|index| mask & (1 << index) != 0)
+                        .map(|index| levels[index])
+                        .collect();
+                    first_violation(&subsequence, &policy).is_none()
+                })
+                .map(u32::count_ones)
+                .max()
+                .unwrap_or(0);
+            prop_assert_eq!(kept.len(), longest as usize);
+        }
+
+        #[test]
+        fn keeps_what_the_dampener_does(
+            levels in prop::collection::vec(-6i32..6, 0..12),
+            policy in policies(Just(usize::MAX)),
+        ) {
+            let removed = dampen(&levels, &policy).unwrap();
+            let kept: Vec<usize> = (0..levels.len())
+                .filter(|index| !removed.contains(index))
+                .collect();
+            prop_assert_eq!(longest_safe(&levels, &policy), kept);
+        }
+    }
+}
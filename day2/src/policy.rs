@@ -0,0 +1,193 @@
+use serde::Deserialize;
+use std::fmt;
+
+use crate::{verdict::Trend, Error};
+
+/// Which way the levels of a report may head.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    Increasing,
+    Decreasing,
+    /// Either way, as long as the whole report agrees with its first change.
+    Either,
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Direction::Increasing => write!(f, "increasing"),
+            Direction::Decreasing => write!(f, "decreasing"),
+            Direction::Either => write!(f, "either direction"),
+        }
+    }
+}
+
+/// The rules a report has to follow to be safe.
+///
+/// Read from TOML or set from the command line, any field left out keeps its
+/// value from [`SafetyPolicy::PART_TWO`], the puzzle's full rules:
+///
+/// ```toml
+/// min_step = 1
+/// max_step = 5
+/// direction = "increasing"
+/// max_removals = 2
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SafetyPolicy {
+    /// The smallest change allowed between adjacent levels.
+    pub min_step: u32,
+    /// The largest change allowed between adjacent levels.
+    pub max_step: u32,
+    pub direction: Direction,
+    /// How many levels the dampener may remove to make a report safe.
+    pub max_removals: usize,
+}
+
+impl SafetyPolicy {
+    /// The puzzle's rules without the dampener.
+    pub const PART_ONE: SafetyPolicy = SafetyPolicy {
+        min_step: 1,
+        max_step: 3,
+        direction: Direction::Either,
+        max_removals: 0,
+    };
+
+    /// The puzzle's rules with a dampener that removes a single level.
+    pub const PART_TWO: SafetyPolicy = SafetyPolicy {
+        max_removals: 1,
+        ..SafetyPolicy::PART_ONE
+    };
+
+    pub fn from_toml(input: &str) -> Result<Self, Error> {
+        let policy: SafetyPolicy = toml::from_str(input)?;
+        policy.validate()
+    }
+
+    /// The policy back, or an error if no step could ever satisfy it.
+    pub fn validate(self) -> Result<Self, Error> {
+        if self.min_step > self.max_step {
+            return Err(Error::StepRange {
+                min_step: self.min_step,
+                max_step: self.max_step,
+            });
+        }
+        Ok(self)
+    }
+
+    pub(crate) fn allows_step(&self, step: u32) -> bool {
+        (self.min_step..=self.max_step).contains(&step)
+    }
+
+    /// Every way a safe report may head under this policy.
+    pub(crate) fn headings(&self) -> &'static [Trend] {
+        match self.direction {
+            Direction::Increasing => &[Trend::Increasing],
+            Direction::Decreasing => &[Trend::Decreasing],
+            Direction::Either => &[Trend::Increasing, Trend::Decreasing],
+        }
+    }
+}
+
+impl Default for SafetyPolicy {
+    fn default() -> Self {
+        SafetyPolicy::PART_TWO
+    }
+}
+
+impl fmt::Display for SafetyPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "steps of {} to {}, {}, up to {} level(s) removed",
+            self.min_step, self.max_step, self.direction, self.max_removals
+        )
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Small policies of every direction for property tests, allowing however
+    /// many removals `max_removals` picks.
+    pub(crate) fn policies(
+        max_removals: impl Strategy<Value = usize>,
+    ) -> impl Strategy<Value = SafetyPolicy> {
+        (
+            0u32..3,
+            0u32..4,
+            prop_oneof![
+                Just(Direction::Increasing),
+                Just(Direction::Decreasing),
+                Just(Direction::Either)
+            ],
+            max_removals,
+        )
+            .prop_map(|(min_step, extra, direction, max_removals)| SafetyPolicy {
+                min_step,
+                max_step: min_step + extra,
+                direction,
+                max_removals,
+            })
+    }
+
+    #[test]
+    fn from_toml() {
+        let policy = SafetyPolicy::from_toml(
+            "min_step = 0\nmax_step = 5\ndirection = \"decreasing\"\nmax_removals = 2\n",
+        )
+        .unwrap();
+        assert_eq!(
+            policy,
+            SafetyPolicy {
+                min_step: 0,
+                max_step: 5,
+                direction: Direction::Decreasing,
+                max_removals: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn missing_fields_keep_part_two_values() {
+        assert_eq!(SafetyPolicy::from_toml("").unwrap(), SafetyPolicy::PART_TWO);
+        assert_eq!(
+            SafetyPolicy::from_toml("max_removals = 0").unwrap(),
+            SafetyPolicy::PART_ONE
+        );
+        assert_eq!(
+            SafetyPolicy::from_toml("max_step = 5").unwrap(),
+            SafetyPolicy {
+                max_step: 5,
+                ..SafetyPolicy::PART_TWO
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_bad_policies() {
+        assert!(matches!(
+            SafetyPolicy::from_toml("min_step = 4"),
+            Err(Error::StepRange {
+                min_step: 4,
+                max_step: 3
+            })
+        ));
+        assert!(matches!(
+            SafetyPolicy::from_toml("direction = \"sideways\""),
+            Err(Error::Policy(_))
+        ));
+        assert!(matches!(
+            SafetyPolicy::from_toml("max_step = -1"),
+            Err(Error::Policy(_))
+        ));
+        assert!(matches!(
+            SafetyPolicy::from_toml("max_steps = 4"),
+            Err(Error::Policy(_))
+        ));
+    }
+}
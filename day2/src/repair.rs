@@ -0,0 +1,429 @@
+use std::cmp::Reverse;
+
+use crate::{first_violation, verdict::Trend, SafetyPolicy};
+
+/// What each kind of fix to a report costs, or `None` where it is not allowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Costs {
+    /// Changing the value of a level.
+    pub edit: Option<u64>,
+    /// Removing a level, as the dampener does.
+    pub delete: Option<u64>,
+}
+
+impl Costs {
+    /// Only value changes, each costing 1, so the cheapest repair is the one
+    /// with the fewest changed levels.
+    pub const EDITS_ONLY: Costs = Costs {
+        edit: Some(1),
+        delete: None,
+    };
+}
+
+impl Default for Costs {
+    fn default() -> Self {
+        Costs::EDITS_ONLY
+    }
+}
+
+/// The cheapest way found to make a report safe.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Repair {
+    /// The report after the repair, without its deleted levels.
+    pub levels: Vec<i32>,
+    /// The indices of the levels whose values changed.
+    pub edited: Vec<usize>,
+    /// The indices of the levels that were removed.
+    pub deleted: Vec<usize>,
+    pub cost: u64,
+}
+
+/// The cheapest way to fix `m` levels when between `lo` and `hi` of them have
+/// to be edited and the rest deleted, as its cost and the number of edits.
+/// Ties go to editing, which keeps more of the report.
+fn cheapest(m: usize, lo: i128, hi: i128, costs: &Costs) -> Option<(u64, usize)> {
+    let (lo, hi) = (lo.max(0), hi.min(m as i128));
+    if lo > hi {
+        return None;
+    }
+    let price = |edits: usize| {
+        let edited = match edits {
+            0 => 0,
+            _ => costs.edit?.checked_mul(edits as u64)?,
+        };
+        let deleted = match m - edits {
+            0 => 0,
+            deletions => costs.delete?.checked_mul(deletions as u64)?,
+        };
+        edited.checked_add(deleted)
+    };
+    // The cost is linear in the number of edits, so one end of the range wins
+    [lo as usize, hi as usize]
+        .into_iter()
+        .filter_map(|edits| price(edits).map(|cost| (cost, edits)))
+        .min_by_key(|&(cost, edits)| (cost, Reverse(edits)))
+}
+
+/// The cheapest repair that leaves `levels` heading `heading`.
+///
+/// Some levels are kept as they are. Between two kept levels that are `d`
+/// apart, `e` edited levels make `e + 1` steps, which can cover any `d` from
+/// `(e + 1) * min_step` to `(e + 1) * max_step`. The best repair with each
+/// level as the last one kept then follows from the ones before it.
+fn repair_heading(
+    levels: &[i32],
+    policy: &SafetyPolicy,
+    costs: &Costs,
+    heading: Trend,
+) -> Option<Repair> {
+    let n = levels.len();
+    if n == 0 {
+        return validated(
+            Repair {
+                levels: Vec::new(),
+                edited: Vec::new(),
+                deleted: Vec::new(),
+                cost: 0,
+            },
+            policy,
+        );
+    }
+    let sign: i128 = match heading {
+        Trend::Increasing => 1,
+        Trend::Decreasing => -1,
+    };
+    let (min, max) = (policy.min_step as i128, policy.max_step as i128);
+    let value = |index: usize| levels[index] as i128;
+    // How far a level can move away from `from` in the direction of `sign`
+    // without leaving the range of an `i32`
+    let room = |from: i128, sign: i128| {
+        if sign > 0 {
+            i32::MAX as i128 - from
+        } else {
+            from - i32::MIN as i128
+        }
+    };
+    let most_edits = |room: i128, m: usize| if min == 0 { m as i128 } else { room / min };
+
+    let between = |j: usize, i: usize| {
+        let (m, d) = (i - j - 1, sign * (value(i) - value(j)));
+        if d < 0 {
+            return None;
+        }
+        let fewest_steps = match max {
+            0 if d == 0 => 1,
+            0 => return None,
+            _ => ((d + max - 1) / max).max(1),
+        };
+        let most_steps = if min == 0 { m as i128 + 1 } else { d / min };
+        cheapest(m, fewest_steps - 1, most_steps - 1, costs)
+    };
+    let before = |i: usize| cheapest(i, 0, most_edits(room(value(i), -sign), i), costs);
+    let after = |i: usize| {
+        let m = n - 1 - i;
+        cheapest(m, 0, most_edits(room(value(i), sign), m), costs)
+    };
+
+    // For each level kept as it is: the cheapest repair up to it, the level
+    // kept before it and how many of the levels in between are edited
+    let mut best: Vec<Option<(u64, Option<usize>, usize)>> = vec![None; n];
+    for i in 0..n {
+        let mut choice = before(i).map(|(cost, edits)| (cost, None, edits));
+        for (j, &earlier) in best[..i].iter().enumerate() {
+            let (Some((so_far, _, _)), Some((cost, edits))) = (earlier, between(j, i)) else {
+                continue;
+            };
+            let Some(total) = so_far.checked_add(cost) else {
+                continue;
+            };
+            if choice.is_none_or(|(cost, _, _)| total < cost) {
+                choice = Some((total, Some(j), edits));
+            }
+        }
+        best[i] = choice;
+    }
+
+    // Keeping nothing as it is means editing levels from one end of the
+    // range of an `i32`, so only so many fit
+    let span = i32::MAX as i128 - i32::MIN as i128;
+    let mut last =
+        cheapest(n, 0, most_edits(span, n - 1) + 1, costs).map(|(cost, edits)| (cost, None, edits));
+    for (i, &kept) in best.iter().enumerate() {
+        let (Some((so_far, _, _)), Some((cost, edits))) = (kept, after(i)) else {
+            continue;
+        };
+        let Some(total) = so_far.checked_add(cost) else {
+            continue;
+        };
+        if last.is_none_or(|(cost, _, _)| total < cost) {
+            last = Some((total, Some(i), edits));
+        }
+    }
+    let (cost, mut kept, trailing_edits) = last?;
+
+    // Walk back through the choices to the levels kept and the edits between
+    let mut chain = Vec::new();
+    let mut gap_edits = trailing_edits;
+    while let Some(i) = kept {
+        let (_, previous, edits) = best[i].expect("kept levels have a repair");
+        chain.push((i, gap_edits));
+        gap_edits = edits;
+        kept = previous;
+    }
+    chain.reverse();
+
+    let mut repair = Repair {
+        levels: Vec::with_capacity(n),
+        edited: Vec::new(),
+        deleted: Vec::new(),
+        cost,
+    };
+    match chain.first() {
+        None => {
+            let start = if sign > 0 { i32::MIN } else { i32::MAX } as i128;
+            repair.fill(0, n, trailing_edits, (0..).map(|t| start + sign * min * t));
+            return validated(repair, policy);
+        }
+        Some(&(first, _)) => {
+            let edits = best[first].expect("kept levels have a repair").2;
+            let end = value(first);
+            repair.fill(
+                0,
+                first,
+                edits,
+                (0..edits as i128).map(|t| end - sign * min * (edits as i128 - t)),
+            );
+        }
+    }
+    for (position, &(kept, edits)) in chain.iter().enumerate() {
+        let from = value(kept);
+        repair.levels.push(from as i32);
+        match chain.get(position + 1) {
+            // Spread the distance to the next kept level as evenly as possible
+            Some(&(next, _)) => {
+                let steps = edits as i128 + 1;
+                let d = sign * (value(next) - from);
+                let (step, longer) = (d / steps, d % steps);
+                let mut level = from;
+                repair.fill(
+                    kept + 1,
+                    next,
+                    edits,
+                    (0..edits as i128).map(|t| {
+                        level += sign * (step + (t < longer) as i128);
+                        level
+                    }),
+                );
+            }
+            None => repair.fill(kept + 1, n, edits, (1..).map(|t| from + sign * min * t)),
+        }
+    }
+    validated(repair, policy)
+}
+
+impl Repair {
+    /// Edits the first `edits` levels from `start`, giving them `values`, and
+    /// deletes the rest up to `end`.
+    fn fill(&mut self, start: usize, end: usize, edits: usize, values: impl Iterator<Item = i128>) {
+        let mut values = values.map(|value| value as i32);
+        for index in start..end {
+            if index < start + edits {
+                self.edited.push(index);
+                self.levels.push(values.next().expect("one value per edit"));
+            } else {
+                self.deleted.push(index);
+            }
+        }
+    }
+}
+
+/// `repair`, as long as the same rules that judge a report agree it is safe.
+fn validated(repair: Repair, policy: &SafetyPolicy) -> Option<Repair> {
+    first_violation(&repair.levels, policy)
+        .is_none()
+        .then_some(repair)
+}
+
+/// The cheapest repair that makes `levels` safe under `policy`, mixing edits
+/// and deletions as `costs` allows. `policy.max_removals` is not used here: how
+/// many levels to delete is down to what deleting costs. `None` if no repair
+/// is possible, which can only happen if neither fix is allowed or the costs
+/// overflow.
+///
+/// This takes O(n²) time for `n` levels.
+pub fn repair(levels: &[i32], policy: &SafetyPolicy, costs: &Costs) -> Option<Repair> {
+    policy
+        .headings()
+        .iter()
+        .filter_map(|&heading| repair_heading(levels, policy, costs, heading))
+        .min_by_key(|repair| repair.cost)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dampen, policy::tests::policies, Direction};
+    use proptest::prelude::*;
+
+    const BOTH: Costs = Costs {
+        edit: Some(2),
+        delete: Some(1),
+    };
+
+    #[test]
+    fn example_repairs() {
+        let policy = SafetyPolicy::PART_ONE;
+        let repair = |levels: &[i32]| super::repair(levels, &policy, &Costs::EDITS_ONLY).unwrap();
+
+        assert_eq!(repair(&[7, 6, 4, 2, 1]).cost, 0);
+        assert_eq!(
+            repair(&[1, 2, 7, 8, 9]),
+            Repair {
+                levels: vec![1, 2, 5, 8, 9],
+                edited: vec![2],
+                deleted: vec![],
+                cost: 1,
+            }
+        );
+        assert_eq!(repair(&[9, 7, 6, 2, 1]).cost, 1);
+        assert_eq!(repair(&[9, 7, 6, 2, 1]).levels, vec![9, 7, 6, 3, 1]);
+        assert_eq!(repair(&[1, 3, 2, 4, 5]).levels, vec![1, 2, 3, 4, 5]);
+        assert_eq!(repair(&[8, 6, 4, 4, 1]).levels, vec![8, 6, 4, 2, 1]);
+    }
+
+    #[test]
+    fn edits_spread_the_gap() {
+        let repair = repair(
+            &[1, 0, 0, 0, 10],
+            &SafetyPolicy::PART_ONE,
+            &Costs::EDITS_ONLY,
+        );
+        assert_eq!(repair.unwrap().levels, vec![1, 4, 6, 8, 10]);
+    }
+
+    #[test]
+    fn mixed_costs() {
+        // Deleting the spike is cheaper than editing it
+        let repair = repair(&[1, 2, 50, 3, 4], &SafetyPolicy::PART_ONE, &BOTH).unwrap();
+        assert_eq!(repair.levels, vec![1, 2, 3, 4]);
+        assert_eq!((repair.deleted, repair.cost), (vec![2], 1));
+
+        // Keeping the 8 means editing one 9 and deleting the other, which
+        // costs as much as deleting all three
+        let repair = super::repair(&[1, 2, 9, 9, 8], &SafetyPolicy::PART_ONE, &BOTH).unwrap();
+        assert_eq!(repair.cost, 3);
+    }
+
+    #[test]
+    fn stays_inside_an_i32() {
+        let increasing = SafetyPolicy {
+            direction: Direction::Increasing,
+            ..SafetyPolicy::PART_ONE
+        };
+        // Only the last level can stay, as nothing fits above it
+        let repair = repair(&[i32::MAX; 3], &increasing, &Costs::EDITS_ONLY).unwrap();
+        assert_eq!(repair.levels, vec![i32::MAX - 2, i32::MAX - 1, i32::MAX]);
+        assert_eq!(repair.edited, vec![0, 1]);
+
+        let repair = super::repair(&[i32::MIN; 3], &increasing, &Costs::EDITS_ONLY).unwrap();
+        assert_eq!(repair.levels, vec![i32::MIN, i32::MIN + 1, i32::MIN + 2]);
+    }
+
+    #[test]
+    fn nothing_allowed() {
+        let nothing = Costs {
+            edit: None,
+            delete: None,
+        };
+        assert!(repair(&[1, 2, 3], &SafetyPolicy::PART_ONE, &nothing).is_some());
+        assert!(repair(&[1, 2, 9], &SafetyPolicy::PART_ONE, &nothing).is_none());
+    }
+
+    /// The fewest edits found by trying every value each level could take,
+    /// which only works for small levels.
+    fn fewest_edits(levels: &[i32], policy: &SafetyPolicy) -> u64 {
+        let reach = (policy.max_step as usize * levels.len()) as i32;
+        let values = -6 - reach..6 + reach;
+        let headings = match policy.direction {
+            Direction::Either => vec![Direction::Increasing, Direction::Decreasing],
+            direction => vec![direction],
+        };
+
+        headings
+            .into_iter()
+            .filter_map(|direction| {
+                let policy = SafetyPolicy {
+                    direction,
+                    ..*policy
+                };
+                let step = |from: i32, to: i32| first_violation(&[from, to], &policy).is_none();
+                // The fewest edits so far for each value of the latest level
+                let mut edits: Vec<Option<u64>> = values
+                    .clone()
+                    .map(|value| levels.first().map(|&level| (level != value) as u64))
+                    .collect();
+                for &level in levels.iter().skip(1) {
+                    edits = values
+                        .clone()
+                        .map(|to| {
+                            values
+                                .clone()
+                                .zip(&edits)
+                                .filter(|&(from, _)| step(from, to))
+                                .filter_map(|(_, &edits)| edits)
+                                .min()
+                                .map(|edits| edits + (level != to) as u64)
+                        })
+                        .collect();
+                }
+                edits.into_iter().flatten().min()
+            })
+            .min()
+            .unwrap_or(0)
+    }
+
+    proptest! {
+        #[test]
+        fn repairs_are_safe_and_add_up(
+            levels in prop::collection::vec(-20i32..20, 0..12),
+            policy in policies(Just(usize::MAX)),
+            edit in 1u64..4,
+            delete in 1u64..4,
+        ) {
+            let costs = Costs { edit: Some(edit), delete: Some(delete) };
+            let repair = repair(&levels, &policy, &costs).unwrap();
+            prop_assert!(first_violation(&repair.levels, &policy).is_none());
+            prop_assert_eq!(
+                repair.cost,
+                edit * repair.edited.len() as u64 + delete * repair.deleted.len() as u64
+            );
+            prop_assert_eq!(repair.levels.len(), levels.len() - repair.deleted.len());
+            for (index, &level) in levels.iter().enumerate() {
+                if !repair.edited.contains(&index) && !repair.deleted.contains(&index) {
+                    prop_assert!(repair.levels.contains(&level));
+                }
+            }
+        }
+
+        #[test]
+        fn deletions_alone_match_the_dampener(
+            levels in prop::collection::vec(-20i32..20, 0..12),
+            policy in policies(Just(usize::MAX)),
+        ) {
+            let costs = Costs { edit: None, delete: Some(1) };
+            let repair = repair(&levels, &policy, &costs).unwrap();
+            let removed = dampen(&levels, &policy).unwrap();
+            prop_assert_eq!(repair.cost as usize, removed.len());
+        }
+
+        #[test]
+        fn edits_alone_are_minimal(
+            levels in prop::collection::vec(-6i32..6, 0..8),
+            policy in policies(Just(usize::MAX)),
+        ) {
+            let repair = repair(&levels, &policy, &Costs::EDITS_ONLY).unwrap();
+            prop_assert!(first_violation(&repair.levels, &policy).is_none());
+            prop_assert_eq!(repair.cost, fewest_edits(&levels, &policy));
+        }
+    }
+}
@@ -0,0 +1,383 @@
+use std::fmt;
+
+use crate::{dampen, Direction, SafetyPolicy};
+
+/// Which way the levels of a report are heading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Increasing,
+    Decreasing,
+}
+
+impl fmt::Display for Trend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Trend::Increasing => write!(f, "increasing"),
+            Trend::Decreasing => write!(f, "decreasing"),
+        }
+    }
+}
+
+/// The first adjacent pair of levels that breaks a rule. `index` is the
+/// 0-based position of `from`, so `to` is at `index + 1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Violation {
+    /// The pair heads the other way to the report's `trend`, which is either
+    /// what the policy demands or set by the first change.
+    Direction {
+        index: usize,
+        from: i32,
+        to: i32,
+        trend: Trend,
+    },
+    /// The pair changes by more or less than the policy allows.
+    Step { index: usize, from: i32, to: i32 },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Violation::Direction {
+                index,
+                from,
+                to,
+                trend,
+            } => write!(
+                f,
+                "{} -> {} at index {} breaks the {} trend",
+                from, to, index, trend
+            ),
+            Violation::Step { index, from, to } => write!(
+                f,
+                "{} -> {} at index {} changes by {}",
+                from,
+                to,
+                index,
+                from.abs_diff(to)
+            ),
+        }
+    }
+}
+
+/// Whether a report is safe, and if not, why.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Safe,
+    /// Unsafe as it stands, but safe once the `removed` levels are taken out,
+    /// each given as its index and value. `violation` is what was wrong before
+    /// the dampener stepped in.
+    Dampened {
+        removed: Vec<(usize, i32)>,
+        violation: Violation,
+    },
+    Unsafe(Violation),
+}
+
+impl Verdict {
+    pub fn is_safe(&self) -> bool {
+        !matches!(self, Verdict::Unsafe(_))
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Safe => write!(f, "safe"),
+            Verdict::Dampened { removed, violation } => {
+                let removed: Vec<String> = removed
+                    .iter()
+                    .map(|(index, level)| format!("{} at index {}", level, index))
+                    .collect();
+                write!(f, "safe without {} ({})", removed.join(", "), violation)
+            }
+            Verdict::Unsafe(violation) => write!(f, "unsafe ({})", violation),
+        }
+    }
+}
+
+pub(crate) fn trend(from: i32, to: i32) -> Option<Trend> {
+    match to.cmp(&from) {
+        std::cmp::Ordering::Greater => Some(Trend::Increasing),
+        std::cmp::Ordering::Less => Some(Trend::Decreasing),
+        std::cmp::Ordering::Equal => None,
+    }
+}
+
+/// The first rule of `policy` that `levels` breaks, or `None` if it is safe
+/// as it stands. Removals are not considered here.
+///
+/// A pair that heads the wrong way is reported as a direction violation even
+/// if its step is also out of range; an unchanged pair only breaks the step
+/// rule.
+pub fn first_violation(levels: &[i32], policy: &SafetyPolicy) -> Option<Violation> {
+    let expected = match policy.direction {
+        Direction::Increasing => Some(Trend::Increasing),
+        Direction::Decreasing => Some(Trend::Decreasing),
+        Direction::Either => levels.windows(2).find_map(|pair| trend(pair[0], pair[1])),
+    };
+
+    levels.windows(2).enumerate().find_map(|(index, pair)| {
+        let (from, to) = (pair[0], pair[1]);
+        match (trend(from, to), expected) {
+            (Some(actual), Some(expected)) if actual != expected => Some(Violation::Direction {
+                index,
+                from,
+                to,
+                trend: expected,
+            }),
+            _ if !policy.allows_step(from.abs_diff(to)) => {
+                Some(Violation::Step { index, from, to })
+            }
+            _ => None,
+        }
+    })
+}
+
+/// Whether `levels` is safe under `policy`, and if not, why.
+///
+/// An unsafe report is retried with as few levels removed as it takes, up to
+/// `policy.max_removals`, picking the earliest levels when there is a choice.
+///
+/// Every report has a verdict. Rules only apply to adjacent pairs, so an empty
+/// or one-level report is always safe, a two-level report is safe if its one
+/// step is, and with any removals allowed a two-level report is always safe by
+/// dropping its first level.
+pub fn judge(levels: &[i32], policy: &SafetyPolicy) -> Verdict {
+    let Some(violation) = first_violation(levels, policy) else {
+        return Verdict::Safe;
+    };
+
+    match dampen(levels, policy) {
+        Some(removed) => Verdict::Dampened {
+            removed: removed
+                .into_iter()
+                .map(|index| (index, levels[index]))
+                .collect(),
+            violation,
+        },
+        None => Verdict::Unsafe(violation),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_verdicts() {
+        assert_eq!(
+            judge(&[7, 6, 4, 2, 1], &SafetyPolicy::PART_ONE),
+            Verdict::Safe
+        );
+        assert_eq!(
+            judge(&[1, 2, 7, 8, 9], &SafetyPolicy::PART_ONE),
+            Verdict::Unsafe(Violation::Step {
+                index: 1,
+                from: 2,
+                to: 7
+            })
+        );
+        assert_eq!(
+            judge(&[1, 3, 2, 4, 5], &SafetyPolicy::PART_ONE),
+            Verdict::Unsafe(Violation::Direction {
+                index: 1,
+                from: 3,
+                to: 2,
+                trend: Trend::Increasing
+            })
+        );
+        assert_eq!(
+            judge(&[8, 6, 4, 4, 1], &SafetyPolicy::PART_ONE),
+            Verdict::Unsafe(Violation::Step {
+                index: 2,
+                from: 4,
+                to: 4
+            })
+        );
+    }
+
+    #[test]
+    fn trend_comes_from_the_first_change() {
+        // The leading repeat is a step violation, but the trend is still
+        // decreasing from there on
+        assert_eq!(
+            first_violation(&[5, 5, 4, 6], &SafetyPolicy::PART_ONE),
+            Some(Violation::Step {
+                index: 0,
+                from: 5,
+                to: 5
+            })
+        );
+        assert_eq!(
+            first_violation(&[1, 2, 9, 3], &SafetyPolicy::PART_ONE),
+            Some(Violation::Step {
+                index: 1,
+                from: 2,
+                to: 9
+            })
+        );
+        assert_eq!(
+            first_violation(&[1, 2, 9, 1], &SafetyPolicy::PART_ONE),
+            Some(Violation::Step {
+                index: 1,
+                from: 2,
+                to: 9
+            })
+        );
+        assert_eq!(
+            first_violation(&[9, 8, 1], &SafetyPolicy::PART_ONE),
+            Some(Violation::Step {
+                index: 1,
+                from: 8,
+                to: 1
+            })
+        );
+        assert_eq!(
+            first_violation(&[1, 2, 1], &SafetyPolicy::PART_ONE),
+            Some(Violation::Direction {
+                index: 1,
+                from: 2,
+                to: 1,
+                trend: Trend::Increasing
+            })
+        );
+    }
+
+    #[test]
+    fn dampened_verdicts() {
+        assert_eq!(
+            judge(&[1, 3, 2, 4, 5], &SafetyPolicy::PART_TWO),
+            Verdict::Dampened {
+                removed: vec![(1, 3)],
+                violation: Violation::Direction {
+                    index: 1,
+                    from: 3,
+                    to: 2,
+                    trend: Trend::Increasing
+                }
+            }
+        );
+        assert!(!judge(&[9, 7, 6, 2, 1], &SafetyPolicy::PART_TWO).is_safe());
+        assert_eq!(
+            judge(&[1, 3, 6, 7, 9], &SafetyPolicy::PART_TWO),
+            Verdict::Safe
+        );
+    }
+
+    #[test]
+    fn fixed_directions() {
+        let increasing = SafetyPolicy {
+            direction: Direction::Increasing,
+            ..SafetyPolicy::PART_ONE
+        };
+        assert_eq!(judge(&[1, 2, 4], &increasing), Verdict::Safe);
+        assert_eq!(
+            judge(&[4, 2, 1], &increasing),
+            Verdict::Unsafe(Violation::Direction {
+                index: 0,
+                from: 4,
+                to: 2,
+                trend: Trend::Increasing
+            })
+        );
+    }
+
+    #[test]
+    fn step_ranges() {
+        let drift = SafetyPolicy {
+            min_step: 0,
+            max_step: 10,
+            ..SafetyPolicy::PART_ONE
+        };
+        assert_eq!(judge(&[1, 1, 11, 11], &drift), Verdict::Safe);
+        assert!(!judge(&[1, 12], &drift).is_safe());
+        // Repeats do not set the trend
+        assert!(!judge(&[5, 5, 6, 4], &drift).is_safe());
+    }
+
+    #[test]
+    fn several_removals() {
+        let policy = SafetyPolicy {
+            max_removals: 2,
+            ..SafetyPolicy::PART_ONE
+        };
+        assert_eq!(
+            judge(&[1, 9, 2, 9, 3], &policy),
+            Verdict::Dampened {
+                removed: vec![(1, 9), (3, 9)],
+                violation: Violation::Step {
+                    index: 0,
+                    from: 1,
+                    to: 9
+                }
+            }
+        );
+        assert!(!judge(&[1, 9, 2, 9, 3], &SafetyPolicy::PART_TWO).is_safe());
+        assert!(!judge(&[1, 9, 9, 9, 2, 3], &policy).is_safe());
+    }
+
+    #[test]
+    fn degenerate_reports() {
+        for policy in [SafetyPolicy::PART_ONE, SafetyPolicy::PART_TWO] {
+            assert_eq!(judge(&[], &policy), Verdict::Safe);
+            assert_eq!(judge(&[5], &policy), Verdict::Safe);
+            assert_eq!(judge(&[5, 7], &policy), Verdict::Safe);
+            assert_eq!(
+                first_violation(&[i32::MIN, i32::MAX], &policy),
+                Some(Violation::Step {
+                    index: 0,
+                    from: i32::MIN,
+                    to: i32::MAX
+                })
+            );
+        }
+        assert_eq!(
+            judge(&[5, 5], &SafetyPolicy::PART_ONE),
+            Verdict::Unsafe(Violation::Step {
+                index: 0,
+                from: 5,
+                to: 5
+            })
+        );
+        assert_eq!(
+            judge(&[5, 5], &SafetyPolicy::PART_TWO),
+            Verdict::Dampened {
+                removed: vec![(0, 5)],
+                violation: Violation::Step {
+                    index: 0,
+                    from: 5,
+                    to: 5
+                }
+            }
+        );
+        assert_eq!(
+            judge(&[i32::MIN, i32::MAX], &SafetyPolicy::PART_TWO).to_string(),
+            "safe without -2147483648 at index 0 (-2147483648 -> 2147483647 at index 0 changes by 4294967295)"
+        );
+        let unlimited = SafetyPolicy {
+            max_removals: usize::MAX,
+            ..SafetyPolicy::PART_ONE
+        };
+        assert!(judge(&[9, 1, 9, 1, 9], &unlimited).is_safe());
+    }
+
+    #[test]
+    fn explanations() {
+        assert_eq!(
+            judge(&[7, 6, 4, 2, 1], &SafetyPolicy::PART_ONE).to_string(),
+            "safe"
+        );
+        assert_eq!(
+            judge(&[9, 7, 6, 2, 1], &SafetyPolicy::PART_ONE).to_string(),
+            "unsafe (6 -> 2 at index 2 changes by 4)"
+        );
+        assert_eq!(
+            judge(&[8, 6, 4, 4, 1], &SafetyPolicy::PART_TWO).to_string(),
+            "safe without 4 at index 2 (4 -> 4 at index 2 changes by 0)"
+        );
+        assert_eq!(
+            judge(&[1, 3, 2], &SafetyPolicy::PART_ONE).to_string(),
+            "unsafe (3 -> 2 at index 1 breaks the increasing trend)"
+        );
+    }
+}
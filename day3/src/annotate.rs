@@ -0,0 +1,66 @@
+use crate::{Instruction, Operation};
+
+const ENABLED: &str = "\x1b[1;32m";
+const DISABLED: &str = "\x1b[31m";
+const CONTROL: &str = "\x1b[1;33m";
+const RESET: &str = "\x1b[0m";
+
+/// `input` with each of its `instructions` highlighted using ANSI colours: the
+/// multiplications part two counts in green, the ones it skips in red, and
+/// `do()` and `don't()` in yellow. The corrupted memory in between is left as
+/// it is.
+///
+/// `instructions` must be in order, as [`crate::parse_input`] returns them.
+pub fn annotate(input: &str, instructions: &[Instruction]) -> String {
+    let mut annotated = String::with_capacity(input.len() * 2);
+    let mut enabled = true;
+    let mut shown = 0;
+
+    for instruction in instructions {
+        let colour = match instruction.operation {
+            Operation::Mul(..) if enabled => ENABLED,
+            Operation::Mul(..) => DISABLED,
+            Operation::Do => {
+                enabled = true;
+                CONTROL
+            }
+            Operation::Dont => {
+                enabled = false;
+                CONTROL
+            }
+        };
+        let span = instruction.span;
+        annotated.push_str(&input[shown..span.offset]);
+        annotated.push_str(colour);
+        annotated.push_str(&input[span.offset..span.end()]);
+        annotated.push_str(RESET);
+        shown = span.end();
+    }
+    annotated.push_str(&input[shown..]);
+    annotated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_input;
+
+    #[test]
+    fn highlights_by_state() {
+        let input = "xmul(2,4)don't()_mul(5,5)do()?mul(8,5)";
+        let parsed = parse_input(input).unwrap();
+        assert_eq!(
+            annotate(input, &parsed),
+            "x\x1b[1;32mmul(2,4)\x1b[0m\x1b[1;33mdon't()\x1b[0m_\x1b[31mmul(5,5)\x1b[0m\
+             \x1b[1;33mdo()\x1b[0m?\x1b[1;32mmul(8,5)\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn leaves_corruption_alone() {
+        let input = "mul[3,7]!^mul(32,64]";
+        let parsed = parse_input(input).unwrap();
+        assert!(parsed.is_empty());
+        assert_eq!(annotate(input, &parsed), input);
+    }
+}
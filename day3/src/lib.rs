@@ -0,0 +1,232 @@
+use aoc_core::Solution;
+use parsing::ParseError;
+
+mod annotate;
+mod scan;
+mod stream;
+
+pub use annotate::annotate;
+pub use stream::{solve_stream, Operations};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Do,
+    Dont,
+    Mul(i32, i32),
+}
+
+/// Where something sits in the input, in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub offset: usize,
+    pub len: usize,
+}
+
+impl Span {
+    pub fn end(&self) -> usize {
+        self.offset + self.len
+    }
+}
+
+/// An operation along with the text it was parsed from, such as `mul(2,4)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub operation: Operation,
+    pub span: Span,
+}
+
+/// Every well-formed operation in the corrupted memory, in order.
+///
+/// Anything else is just corruption rather than an error, so this always
+/// succeeds.
+pub fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let mut scanner = scan::Scanner::NEW;
+    Ok(input
+        .bytes()
+        .enumerate()
+        .filter_map(|(index, byte)| {
+            scanner.step(byte).map(|(operation, len)| Instruction {
+                operation,
+                span: Span {
+                    offset: index + 1 - len,
+                    len,
+                },
+            })
+        })
+        .collect())
+}
+
+/// The worked example from the puzzle description.
+pub const EXAMPLE: &str =
+    "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+/// Both answers, worked out in one pass over the operations so that memory
+/// read as a stream needn't be read twice.
+///
+/// The sums are wide enough that no amount of memory will overflow them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Totals {
+    pub part_one: i128,
+    pub part_two: i128,
+    disabled: bool,
+}
+
+impl Totals {
+    pub fn add(&mut self, operation: Operation) {
+        match operation {
+            Operation::Mul(a, b) => {
+                let product = i128::from(a) * i128::from(b);
+                self.part_one += product;
+                if !self.disabled {
+                    self.part_two += product;
+                }
+            }
+            Operation::Do => self.disabled = false,
+            Operation::Dont => self.disabled = true,
+        }
+    }
+
+    /// The totals for every instruction in `input`.
+    pub fn of(input: &[Instruction]) -> Self {
+        let mut totals = Totals::default();
+        for instruction in input {
+            totals.add(instruction.operation);
+        }
+        totals
+    }
+}
+
+pub fn solve_part_one(input: &[Instruction]) -> i128 {
+    Totals::of(input).part_one
+}
+
+pub fn solve_part_two(input: &[Instruction]) -> i128 {
+    Totals::of(input).part_two
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Instruction>;
+    type Answer = i128;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(solve_part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(solve_part_two(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_many_mul() {
+        let input = "mul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+        let result = parse_input(input);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().len(), 4);
+    }
+
+    #[test]
+    fn part_one() {
+        let input = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+        let parsed = parse_input(input).expect("failed to parse");
+        assert_eq!(solve_part_one(&parsed), 161);
+    }
+
+    #[test]
+    fn part_two() {
+        let input = EXAMPLE;
+        let parsed = parse_input(input).expect("failed to parse");
+        assert_eq!(solve_part_two(&parsed), 48);
+    }
+
+    #[test]
+    fn truncated_operations_are_just_corruption() {
+        let parsed = parse_input("mul(2,4)do()mul(3,").expect("failed to parse");
+        assert_eq!(solve_part_one(&parsed), 8);
+        assert_eq!(parsed.len(), 2);
+    }
+
+    #[test]
+    fn overlapping_and_partial_names() {
+        let parsed = parse_input("mumul(1,2)dodon't()do(mul(3,4)don't(do()mdmul(5,6)")
+            .expect("failed to parse");
+        let operations: Vec<Operation> = parsed.iter().map(|op| op.operation).collect();
+        assert_eq!(
+            operations,
+            vec![
+                Operation::Mul(1, 2),
+                Operation::Dont,
+                Operation::Mul(3, 4),
+                Operation::Do,
+                Operation::Mul(5, 6),
+            ]
+        );
+        assert_eq!(parse_input("no operations here\n").unwrap(), vec![]);
+        assert_eq!(parse_input("").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn spans() {
+        let parsed = parse_input(EXAMPLE).expect("failed to parse");
+        let found: Vec<(Operation, &str)> = parsed
+            .iter()
+            .map(|op| (op.operation, &EXAMPLE[op.span.offset..op.span.end()]))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (Operation::Mul(2, 4), "mul(2,4)"),
+                (Operation::Dont, "don't()"),
+                (Operation::Mul(5, 5), "mul(5,5)"),
+                (Operation::Mul(11, 8), "mul(11,8)"),
+                (Operation::Do, "do()"),
+                (Operation::Mul(8, 5), "mul(8,5)"),
+            ]
+        );
+        assert_eq!(parsed[0].span, Span { offset: 1, len: 8 });
+    }
+
+    #[test]
+    fn products_wider_than_an_i32() {
+        let input = "mul(65536,65536)don't()mul(2147483647,2147483647)";
+        let parsed = parse_input(input).expect("failed to parse");
+        let streamed = solve_stream(input.as_bytes()).unwrap();
+        assert_eq!(
+            solve_part_one(&parsed),
+            4_294_967_296 + 4_611_686_014_132_420_609
+        );
+        assert_eq!(solve_part_two(&parsed), 4_294_967_296);
+        assert_eq!(
+            (streamed.part_one, streamed.part_two),
+            (solve_part_one(&parsed), solve_part_two(&parsed))
+        );
+    }
+
+    #[test]
+    fn malformed_arguments() {
+        let input = "mumul(0012,3)mul(2147483648,1)mul(,1)mul(1,)mmul(2147483647,1)mul(4,5";
+        let parsed = parse_input(input).expect("failed to parse");
+        let found: Vec<(Operation, &str)> = parsed
+            .iter()
+            .map(|op| (op.operation, &input[op.span.offset..op.span.end()]))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (Operation::Mul(12, 3), "mul(0012,3)"),
+                (Operation::Mul(i32::MAX, 1), "mul(2147483647,1)"),
+            ]
+        );
+    }
+}
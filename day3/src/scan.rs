@@ -0,0 +1,119 @@
+use crate::Operation;
+
+/// The names that start an operation. `mul(` still needs its arguments.
+const NAMES: [&[u8]; 3] = [b"mul(", b"do()", b"don't()"];
+
+/// The longest of [`NAMES`].
+const LONGEST: usize = 7;
+
+#[derive(Debug, Clone, Copy)]
+enum State {
+    /// Part way through one of [`NAMES`], having matched `len` bytes of it.
+    Name { matched: [u8; LONGEST], len: usize },
+    /// Reading the first argument of a `mul(`; `None` until there is a digit.
+    Left(Option<i32>),
+    /// Reading the second argument, after the first and its comma.
+    Right(i32, Option<i32>),
+}
+
+impl State {
+    const SCAN: State = State::Name {
+        matched: [0; LONGEST],
+        len: 0,
+    };
+
+    /// Moves on by one byte, returning the operation it completes if any.
+    fn step(&mut self, byte: u8) -> Option<Operation> {
+        let (state, operation) = match *self {
+            State::Name { mut matched, len } => {
+                matched[len] = byte;
+                let prefix = &matched[..=len];
+                if !NAMES.iter().any(|name| name.starts_with(prefix)) {
+                    *self = State::SCAN;
+                    // What was matched before can't start a name, but this byte might
+                    return if len > 0 { self.step(byte) } else { None };
+                }
+                match prefix {
+                    b"mul(" => (State::Left(None), None),
+                    b"do()" => (State::SCAN, Some(Operation::Do)),
+                    b"don't()" => (State::SCAN, Some(Operation::Dont)),
+                    _ => (
+                        State::Name {
+                            matched,
+                            len: len + 1,
+                        },
+                        None,
+                    ),
+                }
+            }
+            State::Left(value) if byte.is_ascii_digit() => match push_digit(value, byte) {
+                Some(value) => (State::Left(Some(value)), None),
+                None => (State::SCAN, None),
+            },
+            State::Left(Some(left)) if byte == b',' => (State::Right(left, None), None),
+            State::Right(left, value) if byte.is_ascii_digit() => match push_digit(value, byte) {
+                Some(value) => (State::Right(left, Some(value)), None),
+                None => (State::SCAN, None),
+            },
+            State::Right(left, Some(right)) if byte == b')' => {
+                (State::SCAN, Some(Operation::Mul(left, right)))
+            }
+            // A malformed `mul(`, which is just more corruption
+            State::Left(_) | State::Right(..) => {
+                *self = State::SCAN;
+                return self.step(byte);
+            }
+        };
+        *self = state;
+        operation
+    }
+}
+
+/// Appends `digit` to a decimal argument, or `None` if it no longer fits.
+fn push_digit(value: Option<i32>, digit: u8) -> Option<i32> {
+    value
+        .unwrap_or(0)
+        .checked_mul(10)?
+        .checked_add(i32::from(digit - b'0'))
+}
+
+/// Finds the operations in corrupted memory one byte at a time, so the memory
+/// can arrive in pieces of any size and needn't be UTF-8.
+///
+/// Only a `mul`, `do()` or `don't()` with nothing out of place counts; the
+/// arguments of a `mul` are decimal numbers that fit in an `i32`. Everything
+/// else is corruption, and a name that turns out to be malformed is scanned
+/// again from the byte that broke it.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Scanner {
+    state: State,
+    /// How many bytes the operation being read has taken so far.
+    len: usize,
+}
+
+impl Scanner {
+    pub(crate) const NEW: Scanner = Scanner {
+        state: State::SCAN,
+        len: 0,
+    };
+
+    /// Moves on by one byte, returning the operation it completes along with
+    /// how many bytes, ending with this one, that operation takes up.
+    #[inline]
+    pub(crate) fn step(&mut self, byte: u8) -> Option<(Operation, usize)> {
+        // Most of the memory is corruption, so get past it quickly
+        if self.len == 0 && byte != b'm' && byte != b'd' {
+            return None;
+        }
+        let operation = self.state.step(byte);
+        let len = self.len + 1;
+        self.len = match self.state {
+            State::Name { len: 0, .. } => 0,
+            // Either this byte started a fresh name or it was thrown away and
+            // then did
+            State::Name { len: 1, .. } => 1,
+            _ => len,
+        };
+        operation.map(|operation| (operation, len))
+    }
+}
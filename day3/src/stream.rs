@@ -0,0 +1,126 @@
+use std::io::{self, BufRead, BufReader, Read};
+
+use crate::{scan::Scanner, Operation, Totals};
+
+/// The operations in corrupted memory read from `R` a chunk at a time.
+///
+/// Yields the same operations as [`crate::parse_input`] would for the whole
+/// memory, from the same scanner, but only ever holds one chunk of it, so the
+/// memory can be as large as you like and needn't be UTF-8. An operation split
+/// across two chunks is still found.
+pub struct Operations<R> {
+    reader: BufReader<R>,
+    scanner: Scanner,
+    failed: bool,
+}
+
+impl<R: Read> Operations<R> {
+    pub fn new(reader: R) -> Self {
+        Operations::with_capacity(64 * 1024, reader)
+    }
+
+    /// Reads `capacity` bytes of memory at a time.
+    pub fn with_capacity(capacity: usize, reader: R) -> Self {
+        Operations {
+            reader: BufReader::with_capacity(capacity, reader),
+            scanner: Scanner::NEW,
+            failed: false,
+        }
+    }
+}
+
+impl<R: Read> Iterator for Operations<R> {
+    type Item = io::Result<Operation>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.failed {
+            let chunk = match self.reader.fill_buf() {
+                Ok([]) => return None,
+                Ok(chunk) => chunk,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    // Nothing after a failed read can be trusted to line up
+                    self.failed = true;
+                    return Some(Err(e));
+                }
+            };
+
+            let scanner = &mut self.scanner;
+            let found = chunk.iter().enumerate().find_map(|(index, &byte)| {
+                scanner
+                    .step(byte)
+                    .map(|(operation, _)| (index + 1, operation))
+            });
+            let read = found.map_or(chunk.len(), |(read, _)| read);
+            self.reader.consume(read);
+            if let Some((_, operation)) = found {
+                return Some(Ok(operation));
+            }
+        }
+        None
+    }
+}
+
+/// Solves both parts for the memory in `reader` without holding on to it.
+pub fn solve_stream(reader: impl Read) -> io::Result<Totals> {
+    let mut totals = Totals::default();
+    for operation in Operations::new(reader) {
+        totals.add(operation?);
+    }
+    Ok(totals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_input, EXAMPLE};
+
+    fn streamed(memory: &[u8], capacity: usize) -> Vec<Operation> {
+        Operations::with_capacity(capacity, memory)
+            .collect::<io::Result<_>>()
+            .expect("reading a slice can't fail")
+    }
+
+    #[test]
+    fn matches_the_parser_at_every_chunk_size() {
+        let memory = "mumul(1,2)dodon't()do(mul(3,4)don't(do()mdmul(5,6)mul(7,8]\
+                      mul(99999999999,2)mul(0012,3)mul(,1)mul(1,)mul(4,5)\u{e9}do()mul(6,";
+        let expected: Vec<Operation> = parse_input(memory)
+            .unwrap()
+            .iter()
+            .map(|instruction| instruction.operation)
+            .collect();
+        for capacity in 1..=memory.len() + 1 {
+            assert_eq!(streamed(memory.as_bytes(), capacity), expected);
+        }
+    }
+
+    #[test]
+    fn tolerates_invalid_utf8() {
+        let memory = b"\xffmul(2,\xfe3)mul(2,3)\xc3don't()\x80mul(4,4)";
+        assert_eq!(
+            streamed(memory, 4),
+            vec![Operation::Mul(2, 3), Operation::Dont, Operation::Mul(4, 4)]
+        );
+    }
+
+    #[test]
+    fn totals() {
+        let totals = solve_stream(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!((totals.part_one, totals.part_two), (161, 48));
+    }
+
+    #[test]
+    fn read_errors_end_the_stream() {
+        struct Broken;
+        impl Read for Broken {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("unplugged"))
+            }
+        }
+
+        let mut operations = Operations::new(Broken);
+        assert!(matches!(operations.next(), Some(Err(_))));
+        assert!(operations.next().is_none());
+    }
+}
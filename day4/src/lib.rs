@@ -0,0 +1,193 @@
+use aoc_core::Solution;
+use grid::{Grid, Point, Vec2};
+use nom::{branch::alt, character::complete::char, combinator::map, IResult};
+use parsing::{grid, parse_all, ParseError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Characters {
+    X,
+    M,
+    A,
+    S,
+}
+
+fn xmas_char(input: &str) -> IResult<&str, Characters> {
+    map(
+        alt((char('X'), char('M'), char('A'), char('S'))),
+        |c| match c {
+            'X' => Characters::X,
+            'M' => Characters::M,
+            'A' => Characters::A,
+            'S' => Characters::S,
+            _ => unreachable!(),
+        },
+    )(input)
+}
+
+pub fn parse_input(input: &str) -> Result<Grid<Characters>, ParseError> {
+    parse_all(grid(xmas_char), input)
+}
+
+/// The worked example from the puzzle description.
+pub const EXAMPLE: &str = "MMMSXXMASM\nMSAMXMSMSA\nAMXSXMAAMM\nMSAMASMSMX\nXMASAMXAMM\nXXAMMXXAMA\nSMSMSASXSS\nSAXAMASAAA\nMAMMMXMMMM\nMXMXAXMASX";
+
+fn get_xmas(input: &Grid<Characters>, starting_cordinates: Point, vector: Vec2) -> bool {
+    let coordinates = [
+        starting_cordinates,
+        starting_cordinates + vector,
+        starting_cordinates + vector * 2,
+        starting_cordinates + vector * 3,
+    ];
+
+    let res: Vec<Characters> = coordinates
+        .iter()
+        .filter_map(|position| input.get(*position).copied())
+        .collect();
+
+    res == [Characters::X, Characters::M, Characters::A, Characters::S]
+}
+
+pub fn solve_part_one(input: &Grid<Characters>) -> i32 {
+    let mut count = 0;
+    for position in input.positions(|c| c == &Characters::X) {
+        for vector in Point::ALL_DIRECTIONS.iter() {
+            if get_xmas(input, position, *vector) {
+                count += 1
+            }
+        }
+    }
+    count
+}
+
+fn get_cross(input: &Grid<Characters>, starting_cordinates: Point) -> bool {
+    let coordinates = [
+        starting_cordinates + Point::UP_LEFT,
+        starting_cordinates + Point::UP_RIGHT,
+        starting_cordinates + Point::DOWN_RIGHT,
+        starting_cordinates + Point::DOWN_LEFT,
+    ];
+
+    let res: Vec<Characters> = coordinates
+        .iter()
+        .filter_map(|position| input.get(*position).copied())
+        .collect();
+
+    // The characters should be MMSS, but possibly rotated
+    let valid_patterns = [
+        vec![Characters::M, Characters::M, Characters::S, Characters::S],
+        vec![Characters::S, Characters::M, Characters::M, Characters::S],
+        vec![Characters::S, Characters::S, Characters::M, Characters::M],
+        vec![Characters::M, Characters::S, Characters::S, Characters::M],
+    ];
+
+    valid_patterns.contains(&res)
+}
+
+pub fn solve_part_two(input: &Grid<Characters>) -> i32 {
+    input
+        .positions(|c| c == &Characters::A)
+        .filter(|position| get_cross(input, *position))
+        .count() as i32
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Grid<Characters>;
+    type Answer = i32;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(solve_part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(solve_part_two(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::error::ErrorKind;
+    use nom::multi::many1;
+
+    #[test]
+    fn parse_many_mul() {
+        let input = "MMMSXXMASM";
+        let result = many1(xmas_char)(input);
+        assert!(result.is_ok());
+        assert_eq!(result.as_ref().unwrap().1.len(), 10);
+        assert_eq!(
+            result.unwrap().1,
+            vec![
+                Characters::M,
+                Characters::M,
+                Characters::M,
+                Characters::S,
+                Characters::X,
+                Characters::X,
+                Characters::M,
+                Characters::A,
+                Characters::S,
+                Characters::M
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_full_input() {
+        let input = EXAMPLE;
+        let result = parse_input(input);
+        assert!(result.is_ok());
+        assert_eq!(
+            result.unwrap().row(0).unwrap(),
+            [
+                Characters::M,
+                Characters::M,
+                Characters::M,
+                Characters::S,
+                Characters::X,
+                Characters::X,
+                Characters::M,
+                Characters::A,
+                Characters::S,
+                Characters::M
+            ]
+        );
+    }
+
+    #[test]
+    fn test_part_one() {
+        let input = EXAMPLE;
+        let parsed = parse_input(input);
+        let solution = solve_part_one(&parsed.unwrap());
+        assert_eq!(solution, 18)
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = EXAMPLE;
+        let parsed = parse_input(input);
+        let solution = solve_part_two(&parsed.unwrap());
+        assert_eq!(solution, 9)
+    }
+
+    #[test]
+    fn rejects_truncated_input() {
+        let error = parse_input("XMAS\nSAMX\nXM").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.kind, ErrorKind::LengthValue);
+    }
+
+    #[test]
+    fn rejects_corrupted_input() {
+        let error = parse_input("XMAS\nSAQX\nXMAS").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.kind, ErrorKind::Char);
+    }
+}
@@ -1,32 +1,33 @@
-use nom::{
-    bytes::complete::tag,
-    character::complete::{digit1, line_ending},
-    combinator::map_res,
-    multi::separated_list1,
-    sequence::separated_pair,
-    IResult,
-};
-use std::{collections::HashMap, fs};
-
-fn parse_number(input: &str) -> IResult<&str, i32> {
-    map_res(digit1, str::parse)(input)
-}
+use aoc_core::Solution;
+use nom::{bytes::complete::tag, multi::separated_list1, sequence::separated_pair, IResult};
+use parsing::{blank_line, integer, lines, parse_all, ParseError};
+use std::collections::HashMap;
+
 fn parse_ordering_rule(input: &str) -> IResult<&str, (i32, i32)> {
-    separated_pair(parse_number, tag("|"), parse_number)(input)
+    separated_pair(integer, tag("|"), integer)(input)
 }
 fn parse_ordering_rules(input: &str) -> IResult<&str, Vec<(i32, i32)>> {
-    separated_list1(line_ending, parse_ordering_rule)(input)
+    lines(parse_ordering_rule)(input)
 }
 
 fn parse_pages(input: &str) -> IResult<&str, Vec<Vec<i32>>> {
-    separated_list1(line_ending, separated_list1(tag(","), parse_number))(input)
+    lines(separated_list1(tag(","), integer))(input)
 }
 
-fn parse_input(input: &str) -> IResult<&str, (Vec<(i32, i32)>, Vec<Vec<i32>>)> {
-    separated_pair(parse_ordering_rules, tag("\n\n"), parse_pages)(input)
+/// Page ordering rules followed by the list of updates.
+pub type Manual = (Vec<(i32, i32)>, Vec<Vec<i32>>);
+
+pub fn parse_input(input: &str) -> Result<Manual, ParseError> {
+    parse_all(
+        separated_pair(parse_ordering_rules, blank_line, parse_pages),
+        input,
+    )
 }
 
-fn solve_part_one((rules, updates): &(Vec<(i32, i32)>, Vec<Vec<i32>>)) -> i32 {
+/// The worked example from the puzzle description.
+pub const EXAMPLE: &str = "47|53\n97|13\n97|61\n97|47\n75|29\n61|13\n75|53\n29|13\n97|29\n53|29\n61|53\n97|53\n61|29\n47|13\n75|47\n97|75\n47|61\n75|61\n47|29\n75|13\n53|13\n\n75,47,61,53,29\n97,61,53,29,13\n75,29,13\n75,97,47,61,53\n61,13,29\n97,13,75,29,47";
+
+pub fn solve_part_one((rules, updates): &Manual) -> i32 {
     let mut rules_map = HashMap::new();
 
     rules.iter().for_each(|(a, b)| {
@@ -50,7 +51,7 @@ fn solve_part_one((rules, updates): &(Vec<(i32, i32)>, Vec<Vec<i32>>)) -> i32 {
     okay_updates.fold(0, |acc, update| acc + update[update.len() / 2])
 }
 
-fn solve_part_two((rules, updates): &(Vec<(i32, i32)>, Vec<Vec<i32>>)) -> i32 {
+pub fn solve_part_two((rules, updates): &Manual) -> i32 {
     let mut rules_map = HashMap::new();
 
     rules.iter().for_each(|(a, b)| {
@@ -100,25 +101,39 @@ fn solve_part_two((rules, updates): &(Vec<(i32, i32)>, Vec<Vec<i32>>)) -> i32 {
     })
 }
 
-fn main() {
-    let input = fs::read_to_string("./input").expect("failed to read");
-    let res = parse_input(&input).expect("failed to parse");
-    println!("part one {:?}", solve_part_one(&res.1));
-    println!("part two {:?}", solve_part_two(&res.1));
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Manual;
+    type Answer = i32;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(solve_part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(solve_part_two(input))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use nom::error::ErrorKind;
 
     #[test]
     fn parse_full_input() {
-        let input = "47|53\n97|13\n97|61\n97|47\n75|29\n61|13\n75|53\n29|13\n97|29\n53|29\n61|53\n97|53\n61|29\n47|13\n75|47\n97|75\n47|61\n75|61\n47|29\n75|13\n53|13\n\n75,47,61,53,29\n97,61,53,29,13\n75,29,13\n75,97,47,61,53\n61,13,29\n97,13,75,29,47";
+        let input = EXAMPLE;
         let result = parse_input(input);
         assert!(result.is_ok());
         assert_eq!(
-            result.unwrap().1,
-            ((
+            result.unwrap(),
+            (
                 vec![
                     (47, 53),
                     (97, 13),
@@ -150,23 +165,38 @@ mod tests {
                     vec![61, 13, 29,],
                     vec![97, 13, 75, 29, 47]
                 ]
-            ))
+            )
         )
     }
 
     #[test]
     fn test_part_one() {
-        let input = "47|53\n97|13\n97|61\n97|47\n75|29\n61|13\n75|53\n29|13\n97|29\n53|29\n61|53\n97|53\n61|29\n47|13\n75|47\n97|75\n47|61\n75|61\n47|29\n75|13\n53|13\n\n75,47,61,53,29\n97,61,53,29,13\n75,29,13\n75,97,47,61,53\n61,13,29\n97,13,75,29,47";
+        let input = EXAMPLE;
         let parsed = parse_input(input).expect("failed to parse");
-        let solution = solve_part_one(&parsed.1);
+        let solution = solve_part_one(&parsed);
         assert_eq!(solution, 143)
     }
 
     #[test]
     fn test_part_two() {
-        let input = "47|53\n97|13\n97|61\n97|47\n75|29\n61|13\n75|53\n29|13\n97|29\n53|29\n61|53\n97|53\n61|29\n47|13\n75|47\n97|75\n47|61\n75|61\n47|29\n75|13\n53|13\n\n75,47,61,53,29\n97,61,53,29,13\n75,29,13\n75,97,47,61,53\n61,13,29\n97,13,75,29,47";
+        let input = EXAMPLE;
         let parsed = parse_input(input).expect("failed to parse");
-        let solution = solve_part_two(&parsed.1);
+        let solution = solve_part_two(&parsed);
         assert_eq!(solution, 123)
     }
+
+    #[test]
+    fn rejects_truncated_input() {
+        let error = parse_input("47|53\n97|").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.kind, ErrorKind::Digit);
+    }
+
+    #[test]
+    fn rejects_corrupted_updates() {
+        // This used to stop at the bad update and quietly drop the rest
+        let error = parse_input("47|53\n97|13\n\n75,47\n61;13\n97,13\n").unwrap_err();
+        assert_eq!((error.line, error.column), (5, 3));
+        assert_eq!(error.snippet, "61;13");
+    }
 }
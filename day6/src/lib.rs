@@ -0,0 +1,227 @@
+use aoc_core::Solution;
+use grid::{Direction, Grid, Point};
+use nom::{branch::alt, bytes::complete::tag, combinator::map, IResult};
+use parsing::{grid, parse_all, ParseError};
+use std::collections::HashSet;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MapThing {
+    Obstacle,
+    Empty,
+    // Player always starts facing up
+    Player(Direction),
+}
+
+fn parse_obstacle(input: &str) -> IResult<&str, MapThing> {
+    map(tag("#"), |_| MapThing::Obstacle)(input)
+}
+fn parse_empty(input: &str) -> IResult<&str, MapThing> {
+    map(tag("."), |_| MapThing::Empty)(input)
+}
+fn parse_player(input: &str) -> IResult<&str, MapThing> {
+    map(tag("^"), |_| MapThing::Player(Direction::Up))(input)
+}
+fn parse_map_thing(input: &str) -> IResult<&str, MapThing> {
+    alt((parse_obstacle, parse_empty, parse_player))(input)
+}
+
+pub fn parse_input(input: &str) -> Result<Grid<MapThing>, ParseError> {
+    parse_all(grid(parse_map_thing), input)
+}
+
+/// The worked example from the puzzle description.
+pub const EXAMPLE: &str = "....#.....\n.........#\n..........\n..#.......\n.......#..\n..........\n.#..^.....\n........#.\n#.........\n......#...";
+
+struct Player {
+    position: Point,
+    direction: Direction,
+}
+struct GameState {
+    player: Player,
+    map: Grid<MapThing>,
+}
+
+impl GameState {
+    fn create(input: &Grid<MapThing>) -> Self {
+        let mut map = input.clone();
+
+        let position = input
+            .find(|item| item == &MapThing::Player(Direction::Up))
+            .unwrap();
+        map[position] = MapThing::Empty;
+
+        GameState {
+            player: Player {
+                position,
+                direction: Direction::Up,
+            },
+            map,
+        }
+    }
+
+    fn out_of_bounds(&self, position: Point) -> bool {
+        !self.map.contains(position)
+    }
+
+    fn tick(mut self) -> Self {
+        loop {
+            let next_position = self.player.position + self.player.direction.delta();
+
+            if let Some(MapThing::Obstacle) = self.map.get(next_position) {
+                self.player.direction = self.player.direction.turn_right();
+            } else {
+                self.player.position = next_position;
+                break;
+            }
+        }
+        self
+    }
+}
+pub fn solve_part_one(input: &Grid<MapThing>) -> i32 {
+    let mut game_state = GameState::create(input);
+    let mut visited = HashSet::new();
+
+    while !game_state.out_of_bounds(game_state.player.position) {
+        visited.insert(game_state.player.position);
+        game_state = game_state.tick();
+    }
+    visited.len() as i32
+}
+
+pub fn solve_part_two(input: &Grid<MapThing>) -> i32 {
+    let test_obstacles: Vec<Point> = input.positions(|item| item == &MapThing::Empty).collect();
+
+    test_obstacles.iter().fold(0, |acc, obstacle| {
+        if check_for_loop(input, *obstacle) {
+            acc + 1
+        } else {
+            acc
+        }
+    })
+}
+
+fn check_for_loop(input: &Grid<MapThing>, obstacle: Point) -> bool {
+    let mut map = input.clone();
+    map[obstacle] = MapThing::Obstacle;
+    // create a new game board, with the test_obstacle added
+    let mut game_state = GameState::create(&map);
+    // create a map of player position and facing. If you are ever in the same position and direction twice you are loopin.
+    let mut visited = HashSet::new();
+
+    while !game_state.out_of_bounds(game_state.player.position) {
+        if visited.contains(&(game_state.player.position, game_state.player.direction)) {
+            return true;
+        }
+
+        visited.insert((game_state.player.position, game_state.player.direction));
+        game_state = game_state.tick();
+    }
+    false
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Parse(ParseError),
+    NoGuard,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(e) => write!(f, "{}", e),
+            Error::NoGuard => write!(f, "the map has no guard (`^`) on it"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Grid<MapThing>;
+    type Answer = i32;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let map = parse_input(input)?;
+        if map
+            .find(|item| matches!(item, MapThing::Player(_)))
+            .is_none()
+        {
+            return Err(Error::NoGuard);
+        }
+        Ok(map)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(solve_part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(solve_part_two(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::error::ErrorKind;
+
+    #[test]
+    fn parse_full_input() {
+        let input = EXAMPLE;
+        let result = parse_input(input);
+        assert!(result.is_ok());
+        assert!(result.unwrap().height() == 10);
+    }
+    #[test]
+    fn part_one() {
+        let input = EXAMPLE;
+        let parsed = parse_input(input).expect("failed to parse");
+        let solution = solve_part_one(&parsed);
+        assert_eq!(solution, 41)
+    }
+
+    #[test]
+    fn check_loops() {
+        let input = EXAMPLE;
+        let parsed = parse_input(input).expect("failed to parse");
+        let solution = check_for_loop(&parsed, Point::new(3, 6));
+        assert!(solution)
+    }
+
+    #[test]
+    fn part_two() {
+        let input = EXAMPLE;
+        let parsed = parse_input(input).expect("failed to parse");
+        let solution = solve_part_two(&parsed);
+        assert_eq!(solution, 6)
+    }
+
+    #[test]
+    fn missing_guard() {
+        let result = Day6::parse("....#\n.....\n#....");
+        assert!(matches!(result, Err(Error::NoGuard)));
+    }
+
+    #[test]
+    fn rejects_truncated_input() {
+        let error = parse_input("#..\n.^.\n..").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.kind, ErrorKind::LengthValue);
+    }
+
+    #[test]
+    fn rejects_corrupted_input() {
+        let error = parse_input("#..\n.^X\n...").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+}
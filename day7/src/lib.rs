@@ -0,0 +1,209 @@
+use aoc_core::Solution;
+use nom::{
+    bytes::complete::tag, character::complete::space1, multi::separated_list1,
+    sequence::separated_pair, IResult,
+};
+use parsing::{lines, parse_all, unsigned, ParseError};
+use std::fmt;
+
+fn parse_line(input: &str) -> IResult<&str, (i64, Vec<i64>)> {
+    separated_pair(unsigned, tag(": "), separated_list1(space1, unsigned))(input)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<(i64, Vec<i64>)>, ParseError> {
+    parse_all(lines(parse_line), input)
+}
+
+/// The worked example from the puzzle description.
+pub const EXAMPLE: &str = "190: 10 19\n3267: 81 40 27\n83: 17 5\n156: 15 6\n7290: 6 8 6 15\n161011: 16 10 13\n192: 17 8 14\n21037: 9 7 18 13\n292: 11 6 16 20";
+
+/// An operator that can combine two numbers, or `None` if the result does
+/// not fit in an `i64`.
+type Operation = fn(i64, i64) -> Option<i64>;
+
+fn add(a: i64, b: i64) -> Option<i64> {
+    a.checked_add(b)
+}
+
+fn multiply(a: i64, b: i64) -> Option<i64> {
+    a.checked_mul(b)
+}
+
+fn combine_numbers(a: i64, b: i64) -> Option<i64> {
+    let digits = b.checked_ilog10().unwrap_or(0) + 1;
+    a.checked_mul(10i64.checked_pow(digits)?)?.checked_add(b)
+}
+
+/// Whether the numbers in `inputs` can be combined left to right onto `acc`
+/// to make `target`.
+///
+/// Numbers are never negative, so nothing but multiplying by a zero can bring
+/// a total back down and anything larger than `target` can be given up on
+/// unless a zero is still to come. A total that overflows is given up on too.
+fn test_line_both(operations: &[Operation], target: i64, inputs: &[i64], acc: i64) -> bool {
+    let Some((&next, remaining)) = inputs.split_first() else {
+        return acc == target;
+    };
+
+    let zero_ahead = remaining.contains(&0);
+    operations
+        .iter()
+        .any(|operation| match operation(acc, next) {
+            Some(result) if result <= target || zero_ahead => {
+                test_line_both(operations, target, remaining, result)
+            }
+            _ => false,
+        })
+}
+
+fn total_calibration(input: &[(i64, Vec<i64>)], operations: &[Operation]) -> i64 {
+    input
+        .iter()
+        .filter(|(target, inputs)| match inputs.split_first() {
+            Some((&first, rest)) => test_line_both(operations, *target, rest, first),
+            None => false,
+        })
+        .map(|(target, _)| target)
+        .sum()
+}
+
+pub fn solve_part_one(input: &[(i64, Vec<i64>)]) -> i64 {
+    total_calibration(input, &[multiply, add])
+}
+
+pub fn solve_part_two(input: &[(i64, Vec<i64>)]) -> i64 {
+    total_calibration(input, &[multiply, add, combine_numbers])
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Parse(ParseError),
+    /// The equation on this (1-based) line has nothing to combine.
+    TooFewNumbers {
+        line: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(e) => write!(f, "{}", e),
+            Error::TooFewNumbers { line } => {
+                write!(f, "line {}: an equation needs at least two numbers", line)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<(i64, Vec<i64>)>;
+    type Answer = i64;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let equations = parse_input(input)?;
+        if let Some(index) = equations.iter().position(|(_, numbers)| numbers.len() < 2) {
+            return Err(Error::TooFewNumbers { line: index + 1 });
+        }
+        Ok(equations)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(solve_part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(solve_part_two(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::error::ErrorKind;
+
+    #[test]
+    fn test_parse_line() {
+        let input = "190: 10 19";
+        let result = parse_line(input);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().1, (190, vec![10, 19]));
+    }
+
+    #[test]
+    fn parse_full_input() {
+        let result = parse_input(EXAMPLE);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().len(), 9);
+    }
+
+    #[test]
+    fn test_solve_part_one() {
+        let result = parse_input(EXAMPLE).expect("failed to parse");
+        let solution = solve_part_one(&result);
+        assert_eq!(solution, 3749);
+    }
+
+    #[test]
+    fn test_solve_part_two() {
+        let result = parse_input(EXAMPLE).expect("failed to parse");
+        let solution = solve_part_two(&result);
+        assert_eq!(solution, 11387);
+    }
+
+    #[test]
+    fn zero_totals_along_the_way() {
+        let equations = parse_input("0: 0 5 3\n8: 0 5 3\n0: 5 3 0").unwrap();
+        assert_eq!(solve_part_one(&equations), 8);
+        assert_eq!(solve_part_two(&equations), 8);
+    }
+
+    #[test]
+    fn overflow_is_too_large() {
+        let equations = parse_input("100: 99999999999 99999999999").unwrap();
+        assert_eq!(solve_part_one(&equations), 0);
+        assert_eq!(solve_part_two(&equations), 0);
+        assert_eq!(combine_numbers(1_000_000_000_000_000_000, 1), None);
+        assert_eq!(combine_numbers(12, 0), Some(120));
+        assert_eq!(combine_numbers(12, 345), Some(12345));
+    }
+
+    #[test]
+    fn single_number_equation() {
+        let result = Day7::parse("190: 10 19\n5: 5");
+        assert!(matches!(result, Err(Error::TooFewNumbers { line: 2 })));
+    }
+
+    #[test]
+    fn rejects_truncated_input() {
+        let error = parse_input("190: 10 19\n3267: ").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 7));
+        assert_eq!(error.kind, ErrorKind::Digit);
+    }
+
+    #[test]
+    fn rejects_negative_numbers() {
+        let error = parse_input("4: 1 -2 2").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 6));
+        let error = parse_input("-4: 1 2").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.kind, ErrorKind::Digit);
+    }
+
+    #[test]
+    fn rejects_corrupted_input() {
+        let error = parse_input("190: 10 19\n3267 81 40 27\n83: 17 5").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(error.kind, ErrorKind::Tag);
+    }
+}
@@ -0,0 +1,208 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::Solution;
+use grid::{Grid, Point};
+use nom::{
+    branch::alt, bytes::complete::tag, character::complete::none_of, combinator::map, IResult,
+};
+use parsing::{grid, parse_all, ParseError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Characters {
+    Empty,
+    Char(char),
+}
+fn parse_cell(input: &str) -> IResult<&str, Characters> {
+    alt((
+        map(tag("."), |_| Characters::Empty),
+        map(none_of("\n\r"), Characters::Char),
+    ))(input)
+}
+
+pub fn parse_input(input: &str) -> Result<Grid<Characters>, ParseError> {
+    parse_all(grid(parse_cell), input)
+}
+
+/// The worked example from the puzzle description.
+pub const EXAMPLE: &str = "............\n........0...\n.....0......\n.......0....\n....0.......\n......A.....\n............\n............\n........A...\n.........A..\n............\n............";
+
+type Pair = (Point, Point);
+
+fn generate_position_pairs(positions: &[Point]) -> Vec<Pair> {
+    let mut pairs = Vec::new();
+    for i in 0..positions.len() {
+        for j in 0..positions.len() {
+            if i != j {
+                pairs.push((positions[i], positions[j]));
+            }
+        }
+    }
+    pairs
+}
+
+fn get_lots_of_antinodes_from_pair(grid: &Grid<Characters>, (first, second): Pair) -> Vec<Point> {
+    let vector = second - first;
+
+    let mut nodes = vec![first, second];
+
+    let mut distance = 1;
+    loop {
+        let next = first + vector * distance;
+
+        if !grid.contains(next) {
+            break;
+        } else {
+            nodes.push(next);
+            distance += 1;
+        }
+    }
+    distance = 1;
+    loop {
+        let next = second - vector * distance;
+
+        if !grid.contains(next) {
+            break;
+        } else {
+            nodes.push(next);
+            distance += 1;
+        }
+    }
+    nodes
+}
+
+fn get_antinodes_from_pair(grid: &Grid<Characters>, (first, second): Pair) -> Vec<Point> {
+    let vector = second - first;
+    [first - vector, second + vector]
+        .iter()
+        .filter(|position| grid.contains(**position))
+        .copied()
+        .collect()
+}
+
+fn antenna_positions(input: &Grid<Characters>) -> HashMap<char, Vec<Point>> {
+    let mut map_of_positions: HashMap<char, Vec<Point>> = HashMap::new();
+    for (position, character) in input.iter() {
+        if let Characters::Char(c) = character {
+            map_of_positions.entry(*c).or_default().push(position);
+        }
+    }
+    map_of_positions
+}
+
+pub fn solve_part_one(input: &Grid<Characters>) -> i32 {
+    let map_of_positions = antenna_positions(input);
+    // Generate all pairs for each character
+    let mut all_pairs: HashSet<Vec<Pair>> = HashSet::new();
+    for positions in map_of_positions.values() {
+        all_pairs.insert(generate_position_pairs(positions));
+    }
+
+    let mut antinodes: HashSet<Point> = HashSet::new();
+    for pairs in all_pairs {
+        for pair in pairs {
+            let antinodes_from_pair = get_antinodes_from_pair(input, pair);
+            antinodes_from_pair.iter().for_each(|x| {
+                antinodes.insert(*x);
+            });
+        }
+    }
+    antinodes.len().try_into().unwrap()
+}
+
+pub fn solve_part_two(input: &Grid<Characters>) -> i32 {
+    let map_of_positions = antenna_positions(input);
+    // Generate all pairs for each character
+    let mut all_pairs: HashSet<Vec<Pair>> = HashSet::new();
+    for positions in map_of_positions.values() {
+        all_pairs.insert(generate_position_pairs(positions));
+    }
+
+    let mut antinodes: HashSet<Point> = HashSet::new();
+    for pairs in all_pairs {
+        for pair in pairs {
+            let antinodes_from_pair = get_lots_of_antinodes_from_pair(input, pair);
+            antinodes_from_pair.iter().for_each(|x| {
+                antinodes.insert(*x);
+            });
+        }
+    }
+
+    antinodes.len().try_into().unwrap()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Grid<Characters>;
+    type Answer = i32;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(solve_part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(solve_part_two(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::error::ErrorKind;
+    use nom::multi::many1;
+
+    #[test]
+    fn parse_line_test() {
+        let input = "......A.....";
+        let result = many1(parse_cell)(input);
+        assert!(result.is_ok());
+        assert_eq!(
+            result.unwrap().1,
+            vec![
+                Characters::Empty,
+                Characters::Empty,
+                Characters::Empty,
+                Characters::Empty,
+                Characters::Empty,
+                Characters::Empty,
+                Characters::Char('A'),
+                Characters::Empty,
+                Characters::Empty,
+                Characters::Empty,
+                Characters::Empty,
+                Characters::Empty,
+            ]
+        );
+    }
+    #[test]
+    fn parse_full_input() {
+        let result = parse_input(EXAMPLE);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_solution_one() {
+        let result = parse_input(EXAMPLE).expect("failed to parse");
+        let solution = solve_part_one(&result);
+        assert_eq!(solution, 14)
+    }
+
+    #[test]
+    fn test_solution_two() {
+        let result = parse_input(EXAMPLE).expect("failed to parse");
+        let solution = solve_part_two(&result);
+        assert_eq!(solution, 34)
+    }
+
+    #[test]
+    fn rejects_truncated_input() {
+        let error = parse_input("....\n.0..\n..").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.kind, ErrorKind::LengthValue);
+    }
+}
@@ -1,43 +1,43 @@
-use std::{fs, iter::repeat};
+use std::{fmt, iter::repeat_n};
 
+use aoc_core::Solution;
 use nom::{
-    character::complete::{anychar, multispace0},
-    combinator::{eof, map, map_res},
+    character::complete::{multispace0, satisfy},
+    combinator::{eof, map},
     multi::many_till,
     sequence::preceded,
     IResult,
 };
+use parsing::{parse_all, ParseError};
 
 const RADIX: u32 = 10;
 fn parse_number(input: &str) -> IResult<&str, i64> {
-    map_res(anychar, |c| {
-        c.to_digit(RADIX).map(|d| d as i64).ok_or("Invalid digit")
+    map(satisfy(|c| c.is_digit(RADIX)), |c| {
+        c.to_digit(RADIX).unwrap() as i64
     })(input)
 }
 
-fn parse_input(input: &str) -> IResult<&str, Vec<i64>> {
-    map(
+pub fn parse_input(input: &str) -> Result<Vec<i64>, ParseError> {
+    let disk_map = map(
         many_till(
             preceded(multispace0, parse_number),
             preceded(multispace0, eof),
         ),
         |(numbers, _)| numbers,
-    )(input)
-}
-fn main() {
-    let input = fs::read_to_string("./input").expect("failed to read");
-    let res = parse_input(&input).expect("failed to parse");
-    println!("part one {:?}", solve_part_one(&res.1));
-    println!("part two {:?}", solve_part_two(&res.1));
+    );
+    parse_all(disk_map, input)
 }
 
+/// The worked example from the puzzle description.
+pub const EXAMPLE: &str = "2333133121414131402";
+
 #[derive(Debug, Copy, Clone)]
 enum NextThing {
     Block,
     Free,
 }
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-enum Thing {
+pub enum Thing {
     Block(i64),
     Free,
 }
@@ -50,12 +50,12 @@ fn convert_input(input: &[i64]) -> Vec<Thing> {
     for size in input {
         match next_thing {
             NextThing::Block => {
-                result.extend(repeat(Thing::Block(next_id)).take(*size as usize));
+                result.extend(repeat_n(Thing::Block(next_id), *size as usize));
                 next_id += 1;
                 next_thing = NextThing::Free;
             }
             NextThing::Free => {
-                result.extend(repeat(Thing::Free).take(*size as usize));
+                result.extend(repeat_n(Thing::Free, *size as usize));
                 next_thing = NextThing::Block;
             }
         }
@@ -64,10 +64,10 @@ fn convert_input(input: &[i64]) -> Vec<Thing> {
     result
 }
 
-fn rearrange(input: &Vec<Thing>) -> Vec<Thing> {
+fn rearrange(input: &[Thing]) -> Vec<Thing> {
     let mut start = 0;
     let mut end = input.len() - 1;
-    let mut result = input.clone();
+    let mut result = input.to_vec();
 
     while start < end {
         match (result[start], result[end]) {
@@ -113,14 +113,14 @@ fn find_zero_block(vec: &[Thing], target_length: usize) -> Option<Vec<usize>> {
     None
 }
 
-fn rearrange_part_two(input: &Vec<Thing>) -> Vec<Thing> {
+fn rearrange_part_two(input: &[Thing]) -> Vec<Thing> {
     let &Thing::Block(id) = input.iter().rev().find(|x| **x != Thing::Free).unwrap() else {
         panic!()
     };
 
     let mut active_id = id;
 
-    let mut result = input.clone();
+    let mut result = input.to_vec();
 
     while active_id >= 0 {
         // Get the indices that contain the active_id
@@ -151,7 +151,7 @@ fn rearrange_part_two(input: &Vec<Thing>) -> Vec<Thing> {
     result
 }
 
-fn get_checksum(input: &Vec<Thing>) -> i64 {
+fn get_checksum(input: &[Thing]) -> i64 {
     input
         .iter()
         .enumerate()
@@ -161,21 +161,69 @@ fn get_checksum(input: &Vec<Thing>) -> i64 {
         })
 }
 
-fn solve_part_one(input: &[i64]) -> i64 {
+pub fn solve_part_one(input: &[i64]) -> i64 {
     get_checksum(&rearrange(&convert_input(input)))
 }
-fn solve_part_two(input: &[i64]) -> i64 {
+pub fn solve_part_two(input: &[i64]) -> i64 {
     get_checksum(&rearrange_part_two(&convert_input(input)))
 }
 
+#[derive(Debug)]
+pub enum Error {
+    Parse(ParseError),
+    NoFiles,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(e) => write!(f, "{}", e),
+            Error::NoFiles => write!(f, "the disk map has no files on it"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<i64>;
+    type Answer = i64;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let disk_map = parse_input(input)?;
+        // File sizes sit at the even positions, free space at the odd ones
+        if disk_map.iter().step_by(2).all(|&size| size == 0) {
+            return Err(Error::NoFiles);
+        }
+        Ok(disk_map)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(solve_part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(solve_part_two(input))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use nom::error::ErrorKind;
 
-    const FULL_INPUT: &str = "2333133121414131402";
     #[test]
     fn parse_full_input() {
-        let result = parse_input(FULL_INPUT);
+        let result = parse_input(EXAMPLE);
         assert!(result.is_ok());
     }
 
@@ -247,15 +295,28 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = parse_input("2333133121414131402").expect("failed to parse");
-        let result = solve_part_one(&input.1);
+        let input = parse_input(EXAMPLE).expect("failed to parse");
+        let result = solve_part_one(&input);
         assert_eq!(result, 1928);
     }
 
     #[test]
     fn test_part_two() {
-        let input = parse_input("2333133121414131402").expect("failed to parse");
-        let result = solve_part_two(&input.1);
+        let input = parse_input(EXAMPLE).expect("failed to parse");
+        let result = solve_part_two(&input);
         assert_eq!(result, 2858);
     }
+
+    #[test]
+    fn no_files() {
+        assert!(matches!(Day9::parse("0302"), Err(Error::NoFiles)));
+        assert!(matches!(Day9::parse("\n"), Err(Error::NoFiles)));
+    }
+
+    #[test]
+    fn rejects_corrupted_input() {
+        let error = parse_input("23331x3\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 6));
+        assert_eq!(error.kind, ErrorKind::Satisfy);
+    }
 }
@@ -0,0 +1,228 @@
+mod direction;
+mod point;
+
+pub use direction::Direction;
+pub use point::{Point, Vec2};
+
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid stored row by row in a single `Vec`.
+///
+/// Positions are signed so that callers can step off the edge and simply get
+/// `None` back rather than having to guard against underflow themselves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, or `None` if they are not all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index_of(&self, position: Point) -> Option<usize> {
+        let x = usize::try_from(position.x).ok()?;
+        let y = usize::try_from(position.y).ok()?;
+        if x < self.width && y < self.height {
+            Some(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    fn position_of(&self, index: usize) -> Point {
+        Point::new((index % self.width) as i32, (index / self.width) as i32)
+    }
+
+    pub fn contains(&self, position: Point) -> bool {
+        self.index_of(position).is_some()
+    }
+
+    pub fn get(&self, position: Point) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Point) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (self.position_of(index), cell))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn row(&self, y: i32) -> Option<&[T]> {
+        let start = self.index_of(Point::new(0, y))?;
+        Some(&self.cells[start..start + self.width])
+    }
+
+    pub fn column(&self, x: i32) -> impl Iterator<Item = &T> + '_ {
+        self.ray(Point::new(x, 0), Point::DOWN)
+            .map(|(_, cell)| cell)
+    }
+
+    /// Cells from `start` moving down and to the right.
+    pub fn diagonal(&self, start: Point) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.ray(start, Point::DOWN_RIGHT)
+    }
+
+    /// Cells from `start` moving down and to the left.
+    pub fn anti_diagonal(&self, start: Point) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.ray(start, Point::DOWN_LEFT)
+    }
+
+    /// Cells from `start` (inclusive) repeatedly stepping by `step` until the
+    /// edge of the grid is reached.
+    pub fn ray(&self, start: Point, step: Vec2) -> impl Iterator<Item = (Point, &T)> + '_ {
+        std::iter::successors(Some(start), move |&position| Some(position + step))
+            .map_while(|position| self.get(position).map(|cell| (position, cell)))
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        position: Point,
+        steps: &'a [Vec2],
+    ) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        steps.iter().filter_map(move |&step| {
+            let neighbour = position + step;
+            self.get(neighbour).map(|cell| (neighbour, cell))
+        })
+    }
+
+    /// The orthogonal neighbours of `position` that are inside the grid.
+    pub fn neighbours4(&self, position: Point) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.neighbours(position, &Point::ORTHOGONAL)
+    }
+
+    /// All surrounding cells of `position` that are inside the grid.
+    pub fn neighbours8(&self, position: Point) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.neighbours(position, &Point::ALL_DIRECTIONS)
+    }
+
+    /// The position of the first cell, row by row, matching `predicate`.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|index| self.position_of(index))
+    }
+
+    /// The positions of every cell matching `predicate`, row by row.
+    pub fn positions<'a>(
+        &'a self,
+        predicate: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Point) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", position))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, position: Point) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbers() -> Grid<i32> {
+        Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap()
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_none());
+    }
+
+    #[test]
+    fn checked_access() {
+        let grid = numbers();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&6));
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 2)), None);
+        assert_eq!(grid[Point::new(1, 0)], 2);
+    }
+
+    #[test]
+    fn neighbours_stay_in_bounds() {
+        let grid = numbers();
+        let four: Vec<i32> = grid.neighbours4(Point::ORIGIN).map(|(_, &n)| n).collect();
+        assert_eq!(four, vec![2, 4]);
+        let eight: Vec<i32> = grid
+            .neighbours8(Point::new(1, 0))
+            .map(|(_, &n)| n)
+            .collect();
+        assert_eq!(eight, vec![3, 6, 5, 4, 1]);
+    }
+
+    #[test]
+    fn lines() {
+        let grid = numbers();
+        assert_eq!(grid.row(1), Some(&[4, 5, 6][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.rows().count(), 2);
+        let diagonal: Vec<i32> = grid.diagonal(Point::ORIGIN).map(|(_, &n)| n).collect();
+        assert_eq!(diagonal, vec![1, 5]);
+        let anti_diagonal: Vec<i32> = grid
+            .anti_diagonal(Point::new(2, 0))
+            .map(|(_, &n)| n)
+            .collect();
+        assert_eq!(anti_diagonal, vec![3, 5]);
+    }
+
+    #[test]
+    fn searching() {
+        let grid = numbers();
+        assert_eq!(grid.find(|&n| n > 4), Some(Point::new(1, 1)));
+        assert_eq!(grid.find(|&n| n > 6), None);
+        assert_eq!(
+            grid.positions(|&n| n % 2 == 0).collect::<Vec<_>>(),
+            vec![Point::new(1, 0), Point::new(0, 1), Point::new(2, 1)]
+        );
+    }
+}
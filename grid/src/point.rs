@@ -0,0 +1,140 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position on a grid, or the offset between two positions.
+///
+/// `x` grows to the right and `y` grows downwards, matching the order rows
+/// appear in puzzle input.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// `Point` used as a displacement rather than a position.
+pub type Vec2 = Point;
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const UP: Point = Point::new(0, -1);
+    pub const UP_RIGHT: Point = Point::new(1, -1);
+    pub const RIGHT: Point = Point::new(1, 0);
+    pub const DOWN_RIGHT: Point = Point::new(1, 1);
+    pub const DOWN: Point = Point::new(0, 1);
+    pub const DOWN_LEFT: Point = Point::new(-1, 1);
+    pub const LEFT: Point = Point::new(-1, 0);
+    pub const UP_LEFT: Point = Point::new(-1, -1);
+
+    /// The four orthogonal unit steps, clockwise from up.
+    pub const ORTHOGONAL: [Point; 4] = [Point::UP, Point::RIGHT, Point::DOWN, Point::LEFT];
+
+    /// The four diagonal unit steps, clockwise from up-right.
+    pub const DIAGONAL: [Point; 4] = [
+        Point::UP_RIGHT,
+        Point::DOWN_RIGHT,
+        Point::DOWN_LEFT,
+        Point::UP_LEFT,
+    ];
+
+    /// All eight unit steps, clockwise from up.
+    pub const ALL_DIRECTIONS: [Point; 8] = [
+        Point::UP,
+        Point::UP_RIGHT,
+        Point::RIGHT,
+        Point::DOWN_RIGHT,
+        Point::DOWN,
+        Point::DOWN_LEFT,
+        Point::LEFT,
+        Point::UP_LEFT,
+    ];
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+}
+
+impl From<(i32, i32)> for Point {
+    fn from((x, y): (i32, i32)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, scale: i32) -> Point {
+        Point::new(self.x * scale, self.y * scale)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Point::new(3, 4);
+        let b = Point::new(1, -2);
+        assert_eq!(a + b, Point::new(4, 2));
+        assert_eq!(a - b, Point::new(2, 6));
+        assert_eq!(b * 3, Point::new(3, -6));
+        assert_eq!(-b, Point::new(-1, 2));
+        assert_eq!(a.manhattan(b), 8);
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+    }
+
+    #[test]
+    fn directions_are_unit_steps() {
+        assert!(Point::ALL_DIRECTIONS
+            .iter()
+            .all(|step| step.x.abs() <= 1 && step.y.abs() <= 1 && *step != Point::ORIGIN));
+        assert!(Point::ORTHOGONAL
+            .iter()
+            .chain(Point::DIAGONAL.iter())
+            .all(|step| Point::ALL_DIRECTIONS.contains(step)));
+    }
+}
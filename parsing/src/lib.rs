@@ -0,0 +1,307 @@
+use grid::Grid;
+use nom::{
+    character::complete::{char, digit1, line_ending, multispace0, space0},
+    combinator::{consumed, map_res, opt, recognize, value},
+    error::ErrorKind,
+    multi::{many1, separated_list1},
+    sequence::{delimited, pair, terminated},
+    IResult, Offset, Parser,
+};
+use std::{fmt, str::FromStr};
+
+type Error<'a> = nom::error::Error<&'a str>;
+
+/// A decimal integer with an optional leading minus sign.
+///
+/// Works for any integer width; unsigned targets simply reject the sign.
+pub fn integer<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(char('-')), digit1)), str::parse)(input)
+}
+
+/// A decimal integer that may not carry a sign.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// One or more lines matched by `line`.
+///
+/// Lines may end in either `\n` or `\r\n`, and spaces or tabs around each line
+/// are ignored. The list ends at a blank line or the end of the input, and the
+/// line ending before it is left for the caller. Any other line that `line`
+/// rejects is an error, so it gets reported where it actually went wrong
+/// rather than as unexpected trailing input.
+pub fn lines<'a, O>(
+    line: impl Parser<&'a str, O, Error<'a>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    let mut line = delimited(space0, line, space0);
+    move |input| {
+        let (mut input, first) = line(input)?;
+        let mut parsed = vec![first];
+
+        while let Ok((next_line, _)) = line_ending::<_, Error>(input) {
+            let (rest, _) = space0(next_line)?;
+            if rest.is_empty() || line_ending::<_, Error>(rest).is_ok() {
+                break;
+            }
+
+            let (rest, output) = line(next_line)?;
+            parsed.push(output);
+            input = rest;
+        }
+
+        Ok((input, parsed))
+    }
+}
+
+/// The separator between two paragraphs: a line ending followed by at least
+/// one empty (or whitespace only) line.
+pub fn blank_line(input: &str) -> IResult<&str, ()> {
+    value((), pair(line_ending, many1(pair(space0, line_ending))))(input)
+}
+
+/// One or more paragraphs matched by `paragraph`, separated by blank lines.
+pub fn paragraphs<'a, O>(
+    paragraph: impl Parser<&'a str, O, Error<'a>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(blank_line, paragraph)
+}
+
+/// A rectangular grid with one `cell` per character position.
+///
+/// A character that `cell` rejects in the middle of a row is reported where it
+/// is; otherwise this fails at the first row whose length differs from the row
+/// above it.
+pub fn grid<'a, T>(
+    cell: impl Parser<&'a str, T, Error<'a>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Grid<T>> {
+    let mut rows = separated_list1(line_ending, consumed(many1(cell)));
+    move |input| {
+        let (remaining, rows) = rows(input)?;
+        for (row, _) in &rows {
+            let after = &input[input.offset(row) + row.len()..];
+            if !after.is_empty() && line_ending::<_, Error>(after).is_err() {
+                return Err(nom::Err::Error(Error::new(after, ErrorKind::Char)));
+            }
+        }
+
+        let width = rows[0].1.len();
+        if let Some((row, _)) = rows.iter().find(|(_, cells)| cells.len() != width) {
+            let row = &input[input.offset(row)..];
+            return Err(nom::Err::Error(Error::new(row, ErrorKind::LengthValue)));
+        }
+
+        let rows = rows.into_iter().map(|(_, cells)| cells).collect();
+        Ok((
+            remaining,
+            Grid::from_rows(rows).expect("row lengths checked"),
+        ))
+    }
+}
+
+/// Where in the input parsing gave up, and what was there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Byte offset into the input.
+    pub offset: usize,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The full text of the offending line, without its line ending.
+    pub snippet: String,
+    pub kind: ErrorKind,
+}
+
+impl ParseError {
+    /// Builds an error for the point in `input` where `remaining` starts.
+    ///
+    /// `remaining` must be a suffix of `input`, as nom errors always are.
+    pub fn new(input: &str, remaining: &str, kind: ErrorKind) -> Self {
+        let offset = input.len() - remaining.len();
+        let consumed = &input[..offset];
+        let line_start = consumed.rfind('\n').map_or(0, |index| index + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |index| offset + index);
+
+        ParseError {
+            offset,
+            line: consumed.matches('\n').count() + 1,
+            column: consumed[line_start..].chars().count() + 1,
+            snippet: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+            kind,
+        }
+    }
+
+    /// Moves an error found by parsing a single line on its own to where that
+    /// line sits in the full input: `line` is its 1-based number and `offset`
+    /// the byte offset it starts at.
+    pub fn on_line(self, line: usize, offset: usize) -> Self {
+        ParseError {
+            offset: offset + self.offset,
+            line: line + self.line - 1,
+            ..self
+        }
+    }
+
+    /// A short description of what went wrong, without the position.
+    pub fn reason(&self) -> &str {
+        match self.kind {
+            ErrorKind::Eof => "unexpected input",
+            ErrorKind::Digit => "expected a number",
+            ErrorKind::MapRes => "number out of range",
+            ErrorKind::Tag
+            | ErrorKind::Char
+            | ErrorKind::OneOf
+            | ErrorKind::NoneOf
+            | ErrorKind::Satisfy => "unexpected character",
+            ErrorKind::LengthValue => "row length differs from the rows above",
+            ErrorKind::CrLf => "expected a line break",
+            ErrorKind::Complete => "unexpected end of input",
+            _ => self.kind.description(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = self.line.to_string().len();
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line,
+            self.column,
+            self.reason()
+        )?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(f, "{:gutter$} | {:>column$}", "", "^", column = self.column)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Runs `parser` over the whole of `input`.
+///
+/// Only trailing whitespace may be left over; anything else is reported as an
+/// error at the point where parsing stopped.
+pub fn parse_all<'a, O>(
+    parser: impl Parser<&'a str, O, Error<'a>>,
+    input: &'a str,
+) -> Result<O, ParseError> {
+    match terminated(parser, multispace0)(input) {
+        Ok(("", output)) => Ok(output),
+        Ok((remaining, _)) => Err(ParseError::new(input, remaining, ErrorKind::Eof)),
+        Err(nom::Err::Error(e)) | Err(nom::Err::Failure(e)) => {
+            Err(ParseError::new(input, e.input, e.code))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::new(input, "", ErrorKind::Complete)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::{character::complete::space1, sequence::separated_pair};
+
+    #[test]
+    fn integers() {
+        assert_eq!(integer::<i32>("-42 rest"), Ok((" rest", -42)));
+        assert_eq!(integer::<i64>("9000000000"), Ok(("", 9_000_000_000)));
+        assert!(integer::<u32>("-1").is_err());
+        assert!(integer::<u8>("256").is_err());
+        assert!(unsigned::<i32>("-1").is_err());
+        assert_eq!(unsigned::<u64>("17,"), Ok((",", 17)));
+    }
+
+    #[test]
+    fn lines_tolerate_crlf_and_padding() {
+        let result =
+            lines(separated_pair(integer::<i32>, space1, integer::<i32>))("1 2\r\n  3   4 \n5 6\n");
+        assert_eq!(result, Ok(("\n", vec![(1, 2), (3, 4), (5, 6)])));
+    }
+
+    #[test]
+    fn paragraphs_split_on_blank_lines() {
+        let result = paragraphs(lines(integer::<i32>))("1\n2\n \n3\r\n4\r\n\r\n5");
+        assert_eq!(result, Ok(("", vec![vec![1, 2], vec![3, 4], vec![5]])));
+    }
+
+    #[test]
+    fn grids() {
+        let (_, parsed) = grid(nom::character::complete::one_of("#."))("#.\n.#").unwrap();
+        assert_eq!(parsed.width(), 2);
+        assert_eq!(parsed.height(), 2);
+        assert!(grid(nom::character::complete::one_of("#."))("#.\n.").is_err());
+    }
+
+    #[test]
+    fn ragged_grids_point_at_the_bad_row() {
+        let error =
+            parse_all(grid(nom::character::complete::one_of("#.")), "#.\n.#\n#\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.kind, ErrorKind::LengthValue);
+    }
+
+    #[test]
+    fn bad_cells_are_reported_where_they_are() {
+        let error = parse_all(
+            grid(nom::character::complete::one_of("#.")),
+            "#.#\n.x.\n#.#",
+        )
+        .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.kind, ErrorKind::Char);
+
+        let error = parse_all(
+            grid(nom::character::complete::one_of("#.")),
+            "#.#\n.#.\n#.x",
+        )
+        .unwrap_err();
+        assert_eq!((error.line, error.column), (3, 3));
+    }
+
+    #[test]
+    fn parse_all_allows_trailing_whitespace() {
+        assert_eq!(parse_all(lines(integer::<i32>), "1\n2\n\n"), Ok(vec![1, 2]));
+    }
+
+    #[test]
+    fn parse_all_reports_where_it_stopped() {
+        let error = parse_all(lines(integer::<i32>), "1\n2\nthree\n").unwrap_err();
+        assert_eq!(error.offset, 4);
+        assert_eq!((error.line, error.column), (3, 1));
+
+        let error = parse_all(lines(integer::<i32>), "1\n22x\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+
+        let pair = separated_pair(integer::<i32>, space1, integer::<i32>);
+        let error = parse_all(lines(pair), "1 2\n3  x\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.kind, ErrorKind::Digit);
+
+        let error = parse_all(integer::<i32>, "x").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.kind, ErrorKind::Digit);
+    }
+
+    #[test]
+    fn errors_can_be_moved_onto_their_line() {
+        let error = parse_all(integer::<i32>, "12x").unwrap_err().on_line(3, 10);
+        assert_eq!(error.offset, 12);
+        assert_eq!((error.line, error.column), (3, 3));
+        assert_eq!(error.snippet, "12x");
+        assert_eq!(error.reason(), "unexpected input");
+    }
+
+    #[test]
+    fn errors_show_the_offending_line() {
+        let error = parse_all(lines(integer::<i32>), "1\r\n22x\r\n3").unwrap_err();
+        assert_eq!(error.snippet, "22x");
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: unexpected input\n2 | 22x\n  |   ^"
+        );
+    }
+}